@@ -0,0 +1,57 @@
+use std::ffi::CString;
+
+use llvm_sys::{
+    core::{
+        LLVMAddFunction, LLVMGetBasicBlockParent, LLVMGetGlobalParent, LLVMGetInsertBlock,
+        LLVMGetNamedFunction,
+    },
+    LLVMBuilder,
+};
+
+use crate::{Function, FunctionType};
+
+#[derive(Copy, Clone)]
+pub enum Allocator {
+    Libc,
+    Custom {
+        malloc: Function<fn(usize) -> *mut u8>,
+        free: Function<fn(*mut u8)>,
+    },
+}
+
+impl Allocator {
+    pub(crate) fn malloc(&self, builder: *mut LLVMBuilder) -> Function<fn(usize) -> *mut u8> {
+        match self {
+            Allocator::Libc => libc_function(builder, "malloc"),
+            Allocator::Custom { malloc, .. } => *malloc,
+        }
+    }
+
+    pub(crate) fn free(&self, builder: *mut LLVMBuilder) -> Function<fn(*mut u8)> {
+        match self {
+            Allocator::Libc => libc_function(builder, "free"),
+            Allocator::Custom { free, .. } => *free,
+        }
+    }
+}
+
+fn libc_function<T: FunctionType>(builder: *mut LLVMBuilder, name: &str) -> Function<T> {
+    let name = CString::new(name).unwrap();
+
+    let function = unsafe {
+        let module = LLVMGetGlobalParent(LLVMGetBasicBlockParent(LLVMGetInsertBlock(builder)));
+        let function = LLVMGetNamedFunction(module, name.to_bytes_with_nul().as_ptr().cast::<i8>());
+
+        if function.is_null() {
+            LLVMAddFunction(
+                module,
+                name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+                T::function_type(),
+            )
+        } else {
+            function
+        }
+    };
+
+    Function::new(function)
+}
@@ -0,0 +1,66 @@
+use llvm_sys::{LLVMAtomicOrdering, LLVMAtomicRMWBinOp};
+
+use crate::types::ValueType;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Ordering {
+    Relaxed,
+    Release,
+    Acquire,
+    AcqRel,
+    SeqCst,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AtomicOp {
+    Xchg,
+    Add,
+    Sub,
+    And,
+    Nand,
+    Or,
+    Xor,
+    Max,
+    Min,
+}
+
+impl From<Ordering> for LLVMAtomicOrdering {
+    fn from(ordering: Ordering) -> Self {
+        match ordering {
+            Ordering::Relaxed => LLVMAtomicOrdering::LLVMAtomicOrderingMonotonic,
+            Ordering::Release => LLVMAtomicOrdering::LLVMAtomicOrderingRelease,
+            Ordering::Acquire => LLVMAtomicOrdering::LLVMAtomicOrderingAcquire,
+            Ordering::AcqRel => LLVMAtomicOrdering::LLVMAtomicOrderingAcquireRelease,
+            Ordering::SeqCst => LLVMAtomicOrdering::LLVMAtomicOrderingSequentiallyConsistent,
+        }
+    }
+}
+
+impl AtomicOp {
+    pub(crate) fn binop(self, signed: bool) -> LLVMAtomicRMWBinOp {
+        match (self, signed) {
+            (AtomicOp::Xchg, _) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpXchg,
+            (AtomicOp::Add, _) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpAdd,
+            (AtomicOp::Sub, _) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpSub,
+            (AtomicOp::And, _) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpAnd,
+            (AtomicOp::Nand, _) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpNand,
+            (AtomicOp::Or, _) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpOr,
+            (AtomicOp::Xor, _) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpXor,
+            (AtomicOp::Max, true) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpMax,
+            (AtomicOp::Max, false) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpUMax,
+            (AtomicOp::Min, true) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpMin,
+            (AtomicOp::Min, false) => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpUMin,
+        }
+    }
+}
+
+pub trait Atomic: ValueType {}
+
+impl Atomic for i8 {}
+impl Atomic for u8 {}
+impl Atomic for i32 {}
+impl Atomic for i64 {}
+impl Atomic for u32 {}
+impl Atomic for u64 {}
+impl Atomic for usize {}
+impl<T: ValueType> Atomic for *mut T {}
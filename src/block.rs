@@ -1,6 +1,66 @@
-use llvm_sys::{core::LLVMPositionBuilderAtEnd, LLVMBasicBlock};
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
 
-use crate::Builder;
+use std::ffi::CString;
+
+use llvm_sys::{
+    core::{
+        LLVMAddIncoming, LLVMAppendBasicBlock, LLVMBlockAddress, LLVMBuildPhi,
+        LLVMGetBasicBlockParent, LLVMGetBasicBlockTerminator, LLVMGetEntryBasicBlock,
+        LLVMGetFirstInstruction, LLVMGetNextInstruction, LLVMIsALandingPadInst, LLVMIsAPHINode,
+        LLVMPositionBuilderAtEnd, LLVMPositionBuilderBefore,
+    },
+    LLVMBasicBlock, LLVMBuilder, LLVMValue,
+};
+
+use crate::{BlockAddress, Builder, BuilderRef, Value, ValueType};
+
+pub trait BlockParams {
+    type Values: Copy;
+
+    fn build_phis(builder: *mut LLVMBuilder) -> Self::Values;
+    fn incoming(values: &Self::Values) -> Vec<*mut LLVMValue>;
+}
+
+macro_rules! block_params {
+    ($($t:ident: $i:tt),*) => {
+        impl<$($t: ValueType),*> BlockParams for ($($t,)*) {
+            type Values = ($(Value<$t>,)*);
+
+            fn build_phis(builder: *mut LLVMBuilder) -> Self::Values {
+                ($(
+                    unsafe {
+                        let name = CString::new("").unwrap();
+
+                        Value::<$t>::new(LLVMBuildPhi(
+                            builder,
+                            $t::value_type(),
+                            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+                        ))
+                    },
+                )*)
+            }
+
+            fn incoming(values: &Self::Values) -> Vec<*mut LLVMValue> {
+                vec![$(values.$i.value()),*]
+            }
+        }
+    };
+}
+
+impl BlockParams for () {
+    type Values = ();
+
+    fn build_phis(_: *mut LLVMBuilder) -> Self::Values {}
+
+    fn incoming(_: &Self::Values) -> Vec<*mut LLVMValue> {
+        Vec::new()
+    }
+}
+
+block_params!(A: 0);
+block_params!(A: 0, B: 1);
+block_params!(A: 0, B: 1, C: 2);
+block_params!(A: 0, B: 1, C: 2, D: 3);
 
 #[derive(Copy, Clone)]
 pub struct Block {
@@ -12,6 +72,16 @@ impl Block {
         Self { value }
     }
 
+    pub(crate) fn append<S: AsRef<str>>(function: *mut LLVMValue, name: S) -> Self {
+        let name = CString::new(name.as_ref()).unwrap();
+
+        let block = unsafe {
+            LLVMAppendBasicBlock(function, name.to_bytes_with_nul().as_ptr().cast::<i8>())
+        };
+
+        Self::new(block)
+    }
+
     pub fn build(&self) -> Builder {
         let builder = Builder::new();
 
@@ -22,7 +92,108 @@ impl Block {
         builder
     }
 
+    pub fn build_at_start(&self) -> BuilderRef<'static> {
+        let builder = Builder::new();
+
+        unsafe {
+            let mut instruction = LLVMGetFirstInstruction(self.value);
+
+            while !instruction.is_null()
+                && (!LLVMIsAPHINode(instruction).is_null()
+                    || !LLVMIsALandingPadInst(instruction).is_null())
+            {
+                instruction = LLVMGetNextInstruction(instruction);
+            }
+
+            if instruction.is_null() {
+                LLVMPositionBuilderAtEnd(builder.builder, self.value);
+            } else {
+                LLVMPositionBuilderBefore(builder.builder, instruction);
+            }
+        }
+
+        BuilderRef::owned(builder)
+    }
+
+    pub fn build_before_terminator(&self) -> BuilderRef<'static> {
+        let builder = Builder::new();
+
+        unsafe {
+            let terminator = LLVMGetBasicBlockTerminator(self.value);
+
+            if terminator.is_null() {
+                LLVMPositionBuilderAtEnd(builder.builder, self.value);
+            } else {
+                LLVMPositionBuilderBefore(builder.builder, terminator);
+            }
+        }
+
+        BuilderRef::owned(builder)
+    }
+
+    pub fn address(&self) -> Value<BlockAddress> {
+        assert!(
+            unsafe { LLVMGetEntryBasicBlock(self.function()) } != self.value,
+            "the entry block of a function cannot have its address taken"
+        );
+
+        unsafe { Value::new(LLVMBlockAddress(self.function(), self.value)) }
+    }
+
     pub(crate) fn value(&self) -> *mut LLVMBasicBlock {
         self.value
     }
+
+    pub(crate) fn function(&self) -> *mut LLVMValue {
+        unsafe { LLVMGetBasicBlockParent(self.value) }
+    }
+}
+
+pub struct ParamBlock<P: BlockParams> {
+    block: Block,
+    phis: P::Values,
+}
+
+impl<P: BlockParams> Clone for ParamBlock<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: BlockParams> Copy for ParamBlock<P> {}
+
+impl<P: BlockParams> ParamBlock<P> {
+    pub(crate) fn append<S: AsRef<str>>(function: *mut LLVMValue, name: S) -> (Self, P::Values) {
+        let block = Block::append(function, name);
+        let phis = P::build_phis(block.build().builder);
+
+        (Self { block, phis }, phis)
+    }
+
+    pub(crate) fn add_incoming(&self, from: *mut LLVMBasicBlock, params: &P::Values) {
+        for (phi, value) in P::incoming(&self.phis).into_iter().zip(P::incoming(params)) {
+            let mut values = [value];
+            let mut blocks = [from];
+
+            unsafe {
+                LLVMAddIncoming(phi, values.as_mut_ptr(), blocks.as_mut_ptr(), 1);
+            }
+        }
+    }
+
+    pub(crate) fn block(&self) -> Block {
+        self.block
+    }
+
+    pub fn build(&self) -> Builder {
+        self.block.build()
+    }
+
+    pub fn build_at_start(&self) -> BuilderRef<'static> {
+        self.block.build_at_start()
+    }
+
+    pub fn build_before_terminator(&self) -> BuilderRef<'static> {
+        self.block.build_before_terminator()
+    }
 }
@@ -1,26 +1,96 @@
-use std::ffi::CString;
+use std::{ffi::CString, marker::PhantomData, ops::Range};
 
 use llvm_sys::{
     core::{
-        LLVMBuildAdd, LLVMBuildAlloca, LLVMBuildBr, LLVMBuildCondBr, LLVMBuildGEP2, LLVMBuildICmp,
-        LLVMBuildIntCast, LLVMBuildLoad2, LLVMBuildMul, LLVMBuildRet, LLVMBuildRetVoid,
-        LLVMBuildSDiv, LLVMBuildSelect, LLVMBuildStore, LLVMBuildStructGEP2, LLVMBuildSub,
-        LLVMBuildUnreachable, LLVMCreateBuilder, LLVMDisposeBuilder, LLVMInt1Type,
+        LLVMAddDestination, LLVMBuildAShr, LLVMBuildAdd, LLVMBuildAlloca, LLVMBuildAnd,
+        LLVMBuildAtomicCmpXchg, LLVMBuildAtomicRMW, LLVMBuildBr, LLVMBuildCondBr,
+        LLVMBuildExactSDiv, LLVMBuildExactUDiv, LLVMBuildExtractElement, LLVMBuildExtractValue,
+        LLVMBuildFence, LLVMBuildGEP2, LLVMBuildICmp, LLVMBuildIndirectBr, LLVMBuildInsertElement,
+        LLVMBuildInsertValue, LLVMBuildIntCast, LLVMBuildIntCast2, LLVMBuildIntToPtr,
+        LLVMBuildInvoke2, LLVMBuildIsNotNull, LLVMBuildIsNull, LLVMBuildLShr, LLVMBuildLoad2,
+        LLVMBuildMemCpy, LLVMBuildMemMove, LLVMBuildMemSet, LLVMBuildMul, LLVMBuildNSWNeg,
+        LLVMBuildNeg, LLVMBuildNot, LLVMBuildOr, LLVMBuildPhi, LLVMBuildPointerCast,
+        LLVMBuildPtrDiff2, LLVMBuildPtrToInt, LLVMBuildResume, LLVMBuildRet, LLVMBuildRetVoid,
+        LLVMBuildSDiv, LLVMBuildSRem, LLVMBuildSelect, LLVMBuildShl, LLVMBuildShuffleVector,
+        LLVMBuildStore, LLVMBuildStructGEP2, LLVMBuildSub, LLVMBuildUDiv, LLVMBuildURem,
+        LLVMBuildUnreachable, LLVMBuildXor, LLVMConstInt, LLVMConstVector, LLVMCreateBuilder,
+        LLVMDisposeBuilder, LLVMGetBasicBlockParent, LLVMGetCalledValue, LLVMGetEntryBasicBlock,
+        LLVMGetFirstInstruction, LLVMGetFunctionCallConv, LLVMGetInsertBlock, LLVMGetMDKindID,
+        LLVMGetNextBasicBlock, LLVMGetNextInstruction, LLVMGetTypeContext, LLVMGetUndef,
+        LLVMGetValueName2, LLVMInt1Type, LLVMInt32Type, LLVMIsACallInst, LLVMIsAUnreachableInst,
+        LLVMMDNodeInContext2, LLVMMetadataAsValue, LLVMMoveBasicBlockAfter,
+        LLVMPositionBuilderAtEnd, LLVMPositionBuilderBefore, LLVMSetAlignment,
+        LLVMSetInstructionCallConv, LLVMSetMetadata, LLVMSetOperand, LLVMSetOrdering,
+        LLVMSetTailCall, LLVMSetVolatile, LLVMSizeOf, LLVMTypeOf, LLVMValueAsMetadata,
     },
-    LLVMBuilder, LLVMIntPredicate,
+    LLVMBasicBlock, LLVMBuilder, LLVMIntPredicate, LLVMType, LLVMValue,
 };
 
 use crate::{
+    allocator::Allocator,
+    atomic::{Atomic, AtomicOp, Ordering},
+    block::{BlockParams, ParamBlock},
+    call::CallOptions,
+    gep::{Field, Path, Step},
+    intrinsic::build_intrinsic_call,
     jump_table::JumpTable,
-    value::{Integer, UntypedValue},
-    Block, Function, FunctionType, Value, ValueType, VariadicFunctionType,
+    memory::{AccessFlags, MemFlags},
+    phi::Phi,
+    tagged_union::{build_payload_ep, build_tag_ep, Enum, Match, PayloadType, Variant, Variants},
+    types,
+    value::{Arithmetic, Constant, Integer, UntypedValue},
+    Block, BlockAddress, Exception, Function, FunctionType, LandingPad, Slice, Value, ValueType,
+    VariadicFunctionType, Vector,
 };
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Predicate {
+    Eq,
+    Ne,
+    Ugt,
+    Uge,
+    Ult,
+    Ule,
+    Sgt,
+    Sge,
+    Slt,
+    Sle,
+}
+
+#[derive(Copy, Clone)]
+pub enum OutOfBounds {
+    Trap,
+    Branch(Block),
+}
+
+impl From<Predicate> for LLVMIntPredicate {
+    fn from(predicate: Predicate) -> Self {
+        match predicate {
+            Predicate::Eq => LLVMIntPredicate::LLVMIntEQ,
+            Predicate::Ne => LLVMIntPredicate::LLVMIntNE,
+            Predicate::Ugt => LLVMIntPredicate::LLVMIntUGT,
+            Predicate::Uge => LLVMIntPredicate::LLVMIntUGE,
+            Predicate::Ult => LLVMIntPredicate::LLVMIntULT,
+            Predicate::Ule => LLVMIntPredicate::LLVMIntULE,
+            Predicate::Sgt => LLVMIntPredicate::LLVMIntSGT,
+            Predicate::Sge => LLVMIntPredicate::LLVMIntSGE,
+            Predicate::Slt => LLVMIntPredicate::LLVMIntSLT,
+            Predicate::Sle => LLVMIntPredicate::LLVMIntSLE,
+        }
+    }
+}
+
 #[must_use]
 pub struct Builder {
     pub(crate) builder: *mut LLVMBuilder,
 }
 
+pub struct BuilderRef<'a> {
+    builder: *mut LLVMBuilder,
+    _owned: Option<Builder>,
+    phantom: PhantomData<&'a mut Builder>,
+}
+
 impl Default for Builder {
     fn default() -> Self {
         Self::new()
@@ -34,100 +104,719 @@ impl Builder {
         Self { builder }
     }
 
+    pub fn current_block(&self) -> Block {
+        Block::new(unsafe { LLVMGetInsertBlock(self.builder) })
+    }
+
+    pub fn by_ref(&mut self) -> BuilderRef<'_> {
+        BuilderRef {
+            builder: self.builder,
+            _owned: None,
+            phantom: PhantomData,
+        }
+    }
+
     pub fn build_call<T: FunctionType>(
-        self,
+        mut self,
         function: &Function<T>,
         params: T::Params,
     ) -> (T::Return, Self) {
-        (build_call(self.builder, function, params), self)
+        let value = self.by_ref().build_call(function, params);
+        (value, self)
     }
 
-    pub fn build_variadic_call<T: VariadicFunctionType>(
+    pub fn build_invoke<T: FunctionType>(
         self,
         function: &Function<T>,
         params: T::Params,
+    ) -> (T::Return, Self, LandingPad) {
+        let (value, unwind) = build_invoke(self.builder, function, params);
+        (value, self, LandingPad::new(unwind.build()))
+    }
+
+    pub fn build_variadic_call<T: VariadicFunctionType>(
+        mut self,
+        function: &Function<T>,
+        params: T::Params,
         variadic_params: &[UntypedValue],
     ) -> (T::Return, Self) {
-        (
-            build_variadic_call(self.builder, function, params, variadic_params),
-            self,
-        )
+        let value = self
+            .by_ref()
+            .build_variadic_call(function, params, variadic_params);
+        (value, self)
+    }
+
+    pub fn build_indirect_call<T: FunctionType + ValueType>(
+        mut self,
+        function: &Value<T>,
+        params: T::Params,
+    ) -> (T::Return, Self) {
+        let value = self.by_ref().build_indirect_call(function, params);
+        (value, self)
+    }
+
+    pub fn build_call_with<T: FunctionType>(
+        mut self,
+        function: &Function<T>,
+        params: T::Params,
+        options: CallOptions,
+    ) -> (T::Return, Self) {
+        let value = self.by_ref().build_call_with(function, params, options);
+        (value, self)
+    }
+
+    pub fn build_indirect_call_with<T: FunctionType + ValueType>(
+        mut self,
+        function: &Value<T>,
+        params: T::Params,
+        options: CallOptions,
+    ) -> (T::Return, Self) {
+        let value = self
+            .by_ref()
+            .build_indirect_call_with(function, params, options);
+        (value, self)
+    }
+
+    pub fn build_indirect_variadic_call<T: VariadicFunctionType + ValueType>(
+        mut self,
+        function: &Value<T>,
+        params: T::Params,
+        variadic_params: &[UntypedValue],
+    ) -> (T::Return, Self) {
+        let value = self
+            .by_ref()
+            .build_indirect_variadic_call(function, params, variadic_params);
+        (value, self)
+    }
+
+    pub fn build_int_cast<T: Integer, U: Integer>(mut self, from: &Value<T>) -> (Value<U>, Self) {
+        let value = self.by_ref().build_int_cast(from);
+        (value, self)
+    }
+
+    pub fn build_add<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_add(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_sub<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_sub(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_mul<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_mul(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_sdiv<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_sdiv(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_exact_sdiv<T: Arithmetic>(
+        mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> (Value<T>, Self) {
+        let value = self.by_ref().build_exact_sdiv(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_udiv<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_udiv(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_exact_udiv<T: Arithmetic>(
+        mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> (Value<T>, Self) {
+        let value = self.by_ref().build_exact_udiv(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_srem<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_srem(lhs, rhs);
+        (value, self)
     }
 
-    pub fn build_int_cast<T: Integer, U: Integer>(self, from: &Value<T>) -> (Value<U>, Self) {
-        (build_int_cast(self.builder, from), self)
+    pub fn build_urem<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_urem(lhs, rhs);
+        (value, self)
     }
 
-    pub fn build_add<T: Integer>(self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
-        (build_add(self.builder, lhs, rhs), self)
+    pub fn build_neg<T: Arithmetic>(mut self, value: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_neg(value);
+        (value, self)
     }
 
-    pub fn build_sub<T: Integer>(self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
-        (build_sub(self.builder, lhs, rhs), self)
+    pub fn build_nsw_neg<T: Arithmetic>(mut self, value: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_nsw_neg(value);
+        (value, self)
     }
 
-    pub fn build_mul<T: Integer>(self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
-        (build_mul(self.builder, lhs, rhs), self)
+    pub fn build_checked_add<T: Arithmetic>(
+        mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> ((Value<T>, Value<T::Bool>), Self) {
+        let value = self.by_ref().build_checked_add(lhs, rhs);
+        (value, self)
     }
 
-    pub fn build_sdiv<T: Integer>(self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
-        (build_sdiv(self.builder, lhs, rhs), self)
+    pub fn build_checked_sub<T: Arithmetic>(
+        mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> ((Value<T>, Value<T::Bool>), Self) {
+        let value = self.by_ref().build_checked_sub(lhs, rhs);
+        (value, self)
     }
 
-    pub fn build_eq<T: Integer>(self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
-        (build_eq(self.builder, lhs, rhs), self)
+    pub fn build_checked_mul<T: Arithmetic>(
+        mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> ((Value<T>, Value<T::Bool>), Self) {
+        let value = self.by_ref().build_checked_mul(lhs, rhs);
+        (value, self)
     }
 
-    pub fn build_lt<T: Integer>(self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
-        (build_lt(self.builder, lhs, rhs), self)
+    pub fn build_saturating_add<T: Arithmetic>(
+        mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> (Value<T>, Self) {
+        let value = self.by_ref().build_saturating_add(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_saturating_sub<T: Arithmetic>(
+        mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> (Value<T>, Self) {
+        let value = self.by_ref().build_saturating_sub(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_and<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_and(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_or<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_or(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_xor<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_xor(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_shl<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_shl(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_shr<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_shr(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_not<T: Arithmetic>(mut self, value: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_not(value);
+        (value, self)
     }
 
-    pub fn build_gt<T: Integer>(self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
-        (build_gt(self.builder, lhs, rhs), self)
+    pub fn build_eq<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_eq(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_lt<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_lt(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_gt<T: Arithmetic>(mut self, lhs: &Value<T>, rhs: &Value<T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_gt(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_compare<T: Arithmetic>(
+        mut self,
+        predicate: Predicate,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> (Value<T::Bool>, Self) {
+        let value = self.by_ref().build_compare(predicate, lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_ptr_compare<T: ValueType>(
+        mut self,
+        predicate: Predicate,
+        lhs: &Value<*mut T>,
+        rhs: &Value<*mut T>,
+    ) -> (Value<bool>, Self) {
+        let value = self.by_ref().build_ptr_compare(predicate, lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_is_null<T: ValueType>(mut self, ptr: &Value<*mut T>) -> (Value<bool>, Self) {
+        let value = self.by_ref().build_is_null(ptr);
+        (value, self)
+    }
+
+    pub fn build_is_not_null<T: ValueType>(mut self, ptr: &Value<*mut T>) -> (Value<bool>, Self) {
+        let value = self.by_ref().build_is_not_null(ptr);
+        (value, self)
     }
 
     pub fn build_conditional_value<T: Integer, U: ValueType>(
-        self,
+        mut self,
         value: &Value<T>,
         t: &Value<U>,
         f: &Value<U>,
     ) -> (Value<U>, Self) {
-        (build_conditional_value(self.builder, value, t, f), self)
+        let value = self.by_ref().build_conditional_value(value, t, f);
+        (value, self)
     }
 
-    pub fn build_load<T: ValueType>(self, ptr: &Value<*mut T>) -> (Value<T>, Self) {
-        (build_load(self.builder, ptr), self)
+    pub fn build_extract_element<T: ValueType, const N: usize, I: Integer>(
+        mut self,
+        vector: &Value<Vector<T, N>>,
+        index: &Value<I>,
+    ) -> (Value<T>, Self) {
+        let value = self.by_ref().build_extract_element(vector, index);
+        (value, self)
     }
 
-    pub fn build_store<T: ValueType>(self, ptr: &Value<*mut T>, value: &Value<T>) -> Self {
-        build_store(self.builder, ptr, value);
+    pub fn build_insert_element<T: ValueType, const N: usize, I: Integer>(
+        mut self,
+        vector: &Value<Vector<T, N>>,
+        value: &Value<T>,
+        index: &Value<I>,
+    ) -> (Value<Vector<T, N>>, Self) {
+        let value = self.by_ref().build_insert_element(vector, value, index);
+        (value, self)
+    }
+
+    pub fn build_shuffle<T: ValueType, const N: usize, const M: usize>(
+        mut self,
+        a: &Value<Vector<T, N>>,
+        b: &Value<Vector<T, N>>,
+        mask: [u32; M],
+    ) -> (Value<Vector<T, M>>, Self) {
+        let value = self.by_ref().build_shuffle(a, b, mask);
+        (value, self)
+    }
+
+    pub fn build_splat<T: ValueType, const N: usize>(
+        mut self,
+        value: &Value<T>,
+    ) -> (Value<Vector<T, N>>, Self) {
+        let value = self.by_ref().build_splat(value);
+        (value, self)
+    }
+
+    pub fn build_phi<T: ValueType>(mut self) -> (Phi<T>, Self) {
+        let value = self.by_ref().build_phi();
+        (value, self)
+    }
+
+    pub fn build_load<T: ValueType>(mut self, ptr: &Value<*mut T>) -> (Value<T>, Self) {
+        let value = self.by_ref().build_load(ptr);
+        (value, self)
+    }
+
+    pub fn build_store<T: ValueType>(mut self, ptr: &Value<*mut T>, value: &Value<T>) -> Self {
+        self.by_ref().build_store(ptr, value);
+        self
+    }
+
+    pub fn build_load_with<T: ValueType>(
+        mut self,
+        ptr: &Value<*mut T>,
+        flags: AccessFlags,
+    ) -> (Value<T>, Self) {
+        let value = self.by_ref().build_load_with(ptr, flags);
+        (value, self)
+    }
+
+    pub fn build_store_with<T: ValueType>(
+        mut self,
+        ptr: &Value<*mut T>,
+        value: &Value<T>,
+        flags: AccessFlags,
+    ) -> Self {
+        self.by_ref().build_store_with(ptr, value, flags);
+        self
+    }
+
+    pub fn build_atomic_load<T: Atomic>(
+        mut self,
+        ptr: &Value<*mut T>,
+        ordering: Ordering,
+    ) -> (Value<T>, Self) {
+        let value = self.by_ref().build_atomic_load(ptr, ordering);
+        (value, self)
+    }
+
+    pub fn build_atomic_store<T: Atomic>(
+        mut self,
+        ptr: &Value<*mut T>,
+        value: &Value<T>,
+        ordering: Ordering,
+    ) -> Self {
+        self.by_ref().build_atomic_store(ptr, value, ordering);
+        self
+    }
+
+    pub fn build_atomic_rmw<T: Integer + Atomic>(
+        mut self,
+        op: AtomicOp,
+        ptr: &Value<*mut T>,
+        value: &Value<T>,
+        ordering: Ordering,
+    ) -> (Value<T>, Self) {
+        let value = self.by_ref().build_atomic_rmw(op, ptr, value, ordering);
+        (value, self)
+    }
+
+    pub fn build_cmpxchg<T: Atomic>(
+        mut self,
+        ptr: &Value<*mut T>,
+        expected: &Value<T>,
+        new: &Value<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> ((Value<T>, Value<bool>), Self) {
+        let value = self
+            .by_ref()
+            .build_cmpxchg(ptr, expected, new, success, failure);
+        (value, self)
+    }
+
+    pub fn build_fence(mut self, ordering: Ordering) -> Self {
+        self.by_ref().build_fence(ordering);
         self
     }
 
     pub fn build_index_load<T: ValueType, const N: usize, I: Integer>(
-        self,
+        mut self,
         array: &Value<*mut [T; N]>,
         index: &Value<I>,
     ) -> (Value<T>, Self) {
-        (build_index_load(self.builder, array, index), self)
+        let value = self.by_ref().build_index_load(array, index);
+        (value, self)
     }
 
     pub fn build_index_store<T: ValueType, const N: usize, I: Integer>(
-        self,
+        mut self,
         array: &Value<*mut [T; N]>,
         index: &Value<I>,
         value: &Value<T>,
     ) -> Self {
-        build_index_store(self.builder, array, index, value);
+        self.by_ref().build_index_store(array, index, value);
         self
     }
 
-    pub fn build_struct<A: ValueType, B: ValueType>(
+    pub fn build_slice<T: ValueType>(
+        mut self,
+        ptr: &Value<*mut T>,
+        len: &Value<usize>,
+    ) -> (Value<Slice<T>>, Self) {
+        let value = self.by_ref().build_slice(ptr, len);
+        (value, self)
+    }
+
+    pub fn build_array_slice<T: ValueType, const N: usize>(
+        mut self,
+        array: &Value<*mut [T; N]>,
+    ) -> (Value<Slice<T>>, Self) {
+        let value = self.by_ref().build_array_slice(array);
+        (value, self)
+    }
+
+    pub fn build_slice_ptr<T: ValueType>(
+        mut self,
+        slice: &Value<Slice<T>>,
+    ) -> (Value<*mut T>, Self) {
+        let value = self.by_ref().build_slice_ptr(slice);
+        (value, self)
+    }
+
+    pub fn build_slice_len<T: ValueType>(
+        mut self,
+        slice: &Value<Slice<T>>,
+    ) -> (Value<usize>, Self) {
+        let value = self.by_ref().build_slice_len(slice);
+        (value, self)
+    }
+
+    pub fn build_slice_index_load<T: ValueType, I: Integer>(
+        self,
+        slice: &Value<Slice<T>>,
+        index: &Value<I>,
+        out_of_bounds: OutOfBounds,
+    ) -> (Value<T>, Self) {
+        (
+            build_slice_index_load(self.builder, slice, index, out_of_bounds),
+            self,
+        )
+    }
+
+    pub fn build_slice_index_store<T: ValueType, I: Integer>(
         self,
+        slice: &Value<Slice<T>>,
+        index: &Value<I>,
+        value: &Value<T>,
+        out_of_bounds: OutOfBounds,
+    ) -> Self {
+        build_slice_index_store(self.builder, slice, index, value, out_of_bounds);
+        self
+    }
+
+    pub fn build_gep<T: ValueType, P: Path<T>>(
+        mut self,
+        ptr: &Value<*mut T>,
+        path: P,
+    ) -> (Value<*mut P::Output>, Self) {
+        let value = self.by_ref().build_gep(ptr, path);
+        (value, self)
+    }
+
+    pub fn build_extract_field<T: ValueType, const I: usize>(
+        mut self,
+        aggregate: &Value<T>,
+        field: Field<I>,
+    ) -> (Value<<Field<I> as Step<T>>::Output>, Self)
+    where
+        Field<I>: Step<T>,
+    {
+        let value = self.by_ref().build_extract_field(aggregate, field);
+        (value, self)
+    }
+
+    pub fn build_ptr_offset<T: ValueType, I: Integer>(
+        mut self,
+        ptr: &Value<*mut T>,
+        index: &Value<I>,
+    ) -> (Value<*mut T>, Self) {
+        let value = self.by_ref().build_ptr_offset(ptr, index);
+        (value, self)
+    }
+
+    pub fn build_ptr_diff<T: ValueType>(
+        mut self,
+        lhs: &Value<*mut T>,
+        rhs: &Value<*mut T>,
+    ) -> (Value<i64>, Self) {
+        let value = self.by_ref().build_ptr_diff(lhs, rhs);
+        (value, self)
+    }
+
+    pub fn build_ptr_to_int<T: ValueType, I: Integer>(
+        mut self,
+        ptr: &Value<*mut T>,
+    ) -> (Value<I>, Self) {
+        let value = self.by_ref().build_ptr_to_int(ptr);
+        (value, self)
+    }
+
+    pub fn build_int_to_ptr<I: Integer, T: ValueType>(
+        mut self,
+        value: &Value<I>,
+    ) -> (Value<*mut T>, Self) {
+        let value = self.by_ref().build_int_to_ptr(value);
+        (value, self)
+    }
+
+    pub fn build_malloc<T: ValueType>(mut self, allocator: &Allocator) -> (Value<*mut T>, Self) {
+        let value = self.by_ref().build_malloc(allocator);
+        (value, self)
+    }
+
+    pub fn build_array_malloc<T: ValueType, I: Integer>(
+        mut self,
+        allocator: &Allocator,
+        count: &Value<I>,
+    ) -> (Value<*mut T>, Self) {
+        let value = self.by_ref().build_array_malloc(allocator, count);
+        (value, self)
+    }
+
+    pub fn build_free<T: ValueType>(mut self, allocator: &Allocator, ptr: &Value<*mut T>) -> Self {
+        self.by_ref().build_free(allocator, ptr);
+        self
+    }
+
+    pub fn build_memset<T: ValueType>(
+        mut self,
+        ptr: &Value<*mut T>,
+        value: &Value<u8>,
+        flags: MemFlags,
+    ) -> Self {
+        self.by_ref().build_memset(ptr, value, flags);
+        self
+    }
+
+    pub fn build_memcpy<T: ValueType>(
+        mut self,
+        dst: &Value<*mut T>,
+        src: &Value<*mut T>,
+        flags: MemFlags,
+    ) -> Self {
+        self.by_ref().build_memcpy(dst, src, flags);
+        self
+    }
+
+    pub fn build_memmove<T: ValueType>(
+        mut self,
+        dst: &Value<*mut T>,
+        src: &Value<*mut T>,
+        flags: MemFlags,
+    ) -> Self {
+        self.by_ref().build_memmove(dst, src, flags);
+        self
+    }
+
+    pub fn build_array_memset<T: ValueType, I: Integer>(
+        mut self,
+        ptr: &Value<*mut T>,
+        value: &Value<u8>,
+        count: &Value<I>,
+        flags: MemFlags,
+    ) -> Self {
+        self.by_ref().build_array_memset(ptr, value, count, flags);
+        self
+    }
+
+    pub fn build_array_memcpy<T: ValueType, I: Integer>(
+        mut self,
+        dst: &Value<*mut T>,
+        src: &Value<*mut T>,
+        count: &Value<I>,
+        flags: MemFlags,
+    ) -> Self {
+        self.by_ref().build_array_memcpy(dst, src, count, flags);
+        self
+    }
+
+    pub fn build_array_memmove<T: ValueType, I: Integer>(
+        mut self,
+        dst: &Value<*mut T>,
+        src: &Value<*mut T>,
+        count: &Value<I>,
+        flags: MemFlags,
+    ) -> Self {
+        self.by_ref().build_array_memmove(dst, src, count, flags);
+        self
+    }
+
+    pub fn build_local<T: ValueType, S: AsRef<str>>(mut self, name: S) -> (Value<*mut T>, Self) {
+        let value = self.by_ref().build_local(name);
+        (value, self)
+    }
+
+    pub fn build_struct<A: ValueType, B: ValueType>(
+        mut self,
         a: &Value<A>,
         b: &Value<B>,
     ) -> (Value<*mut (A, B)>, Self) {
-        (build_struct(self.builder, a, b), self)
+        let value = self.by_ref().build_struct(a, b);
+        (value, self)
+    }
+
+    pub fn build_variant<V: Variant<I>, const I: usize>(
+        mut self,
+        payload: &Value<V::Payload>,
+    ) -> (Value<*mut Enum<V>>, Self)
+    where
+        V::Payload: PayloadType,
+    {
+        let value = self.by_ref().build_variant(payload);
+        (value, self)
+    }
+
+    pub fn build_unit_variant<V: Variant<I, Payload = ()>, const I: usize>(
+        mut self,
+    ) -> (Value<*mut Enum<V>>, Self) {
+        let value = self.by_ref().build_unit_variant();
+        (value, self)
+    }
+
+    pub fn build_store_variant<V: Variant<I>, const I: usize>(
+        mut self,
+        ptr: &Value<*mut Enum<V>>,
+        payload: &Value<V::Payload>,
+    ) -> Self
+    where
+        V::Payload: PayloadType,
+    {
+        self.by_ref().build_store_variant(ptr, payload);
+        self
+    }
+
+    pub fn build_store_tag<V: Variant<I>, const I: usize>(
+        mut self,
+        ptr: &Value<*mut Enum<V>>,
+    ) -> Self {
+        self.by_ref().build_store_tag(ptr);
+        self
+    }
+
+    pub fn build_load_tag<V: Variants>(mut self, ptr: &Value<*mut Enum<V>>) -> (Value<u32>, Self) {
+        let value = self.by_ref().build_load_tag(ptr);
+        (value, self)
+    }
+
+    pub fn build_match<V: Variants>(self, ptr: &Value<*mut Enum<V>>, default: &Block) -> Match<V> {
+        let tag = build_load_tag(self.builder, ptr);
+        Match::new(self.build_jump_table(&tag, default), ptr)
+    }
+
+    pub fn build_if<C, T, F, G>(self, cond: &Value<C>, then: F, else_: G) -> (Value<T>, Self)
+    where
+        C: Integer,
+        T: ValueType,
+        F: FnOnce(Builder) -> (Value<T>, Builder),
+        G: FnOnce(Builder) -> (Value<T>, Builder),
+    {
+        (build_if(self.builder, cond, then, else_), self)
+    }
+
+    pub fn build_if_then<C, F>(self, cond: &Value<C>, then: F) -> Self
+    where
+        C: Integer,
+        F: FnOnce(Builder) -> Builder,
+    {
+        build_if_then(self.builder, cond, then);
+        self
+    }
+
+    pub fn build_while<C, F, G>(self, cond: F, body: G) -> Self
+    where
+        C: Integer,
+        F: FnOnce(Builder) -> (Value<C>, Builder),
+        G: FnOnce(Builder) -> Builder,
+    {
+        build_while(self.builder, cond, body);
+        self
+    }
+
+    pub fn build_for<T, F>(self, range: Range<Value<T>>, body: F) -> Self
+    where
+        T: Integer,
+        F: FnOnce(Builder, Value<T>) -> Builder,
+    {
+        build_for(self.builder, range, body);
+        self
     }
 
     pub fn build_jump_table<T: ValueType>(
@@ -138,10 +827,33 @@ impl Builder {
         JumpTable::new(self, switch.value(), default)
     }
 
+    pub fn build_exception_ptr(mut self, exception: &Value<Exception>) -> (Value<*mut u8>, Self) {
+        let value = self.by_ref().build_exception_ptr(exception);
+        (value, self)
+    }
+
+    pub fn build_exception_selector(mut self, exception: &Value<Exception>) -> (Value<i32>, Self) {
+        let value = self.by_ref().build_exception_selector(exception);
+        (value, self)
+    }
+
+    pub fn build_type_id(mut self, type_info: &Value<*mut u8>) -> (Value<i32>, Self) {
+        let value = self.by_ref().build_type_id(type_info);
+        (value, self)
+    }
+
+    pub fn build_resume(self, exception: &Value<Exception>) {
+        build_resume(self.builder, exception);
+    }
+
     pub fn build_unreachable(self) {
         build_unreachable(self.builder);
     }
 
+    pub fn build_indirect_branch(self, address: &Value<BlockAddress>, destinations: &[&Block]) {
+        build_indirect_branch(self.builder, address, destinations);
+    }
+
     pub fn build_jump(self, block: &Block) {
         build_jump(self.builder, block);
     }
@@ -150,6 +862,21 @@ impl Builder {
         build_conditional_jump(self.builder, value, t, f);
     }
 
+    pub fn build_jump_with_params<P: BlockParams>(self, block: &ParamBlock<P>, params: P::Values) {
+        build_jump_with_params(self.builder, block, params);
+    }
+
+    pub fn build_conditional_jump_with_params<T: Integer, P: BlockParams, Q: BlockParams>(
+        self,
+        value: &Value<T>,
+        t: &ParamBlock<P>,
+        t_params: P::Values,
+        f: &ParamBlock<Q>,
+        f_params: Q::Values,
+    ) {
+        build_conditional_jump_with_params(self.builder, value, t, t_params, f, f_params);
+    }
+
     pub fn build_ret<T: ValueType>(self, value: &Value<T>) {
         build_ret(self.builder, value);
     }
@@ -159,22 +886,609 @@ impl Builder {
     }
 }
 
-impl Drop for Builder {
-    fn drop(&mut self) {
-        unsafe {
-            LLVMDisposeBuilder(self.builder);
+impl BuilderRef<'static> {
+    pub(crate) fn owned(builder: Builder) -> Self {
+        Self {
+            builder: builder.builder,
+            _owned: Some(builder),
+            phantom: PhantomData,
         }
     }
 }
 
-fn build_call<T: FunctionType>(
-    builder: *mut LLVMBuilder,
+impl<'a> BuilderRef<'a> {
+    pub fn current_block(&self) -> Block {
+        Block::new(unsafe { LLVMGetInsertBlock(self.builder) })
+    }
+
+    pub fn build_call<T: FunctionType>(
+        &mut self,
+        function: &Function<T>,
+        params: T::Params,
+    ) -> T::Return {
+        build_call(self.builder, function, params)
+    }
+
+    pub fn build_variadic_call<T: VariadicFunctionType>(
+        &mut self,
+        function: &Function<T>,
+        params: T::Params,
+        variadic_params: &[UntypedValue],
+    ) -> T::Return {
+        build_variadic_call(self.builder, function, params, variadic_params)
+    }
+
+    pub fn build_indirect_call<T: FunctionType + ValueType>(
+        &mut self,
+        function: &Value<T>,
+        params: T::Params,
+    ) -> T::Return {
+        build_indirect_call(self.builder, function, params)
+    }
+
+    pub fn build_call_with<T: FunctionType>(
+        &mut self,
+        function: &Function<T>,
+        params: T::Params,
+        options: CallOptions,
+    ) -> T::Return {
+        build_call_with::<T>(self.builder, function.value(), params, options)
+    }
+
+    pub fn build_indirect_call_with<T: FunctionType + ValueType>(
+        &mut self,
+        function: &Value<T>,
+        params: T::Params,
+        options: CallOptions,
+    ) -> T::Return {
+        build_call_with::<T>(self.builder, function.value(), params, options)
+    }
+
+    pub fn build_indirect_variadic_call<T: VariadicFunctionType + ValueType>(
+        &mut self,
+        function: &Value<T>,
+        params: T::Params,
+        variadic_params: &[UntypedValue],
+    ) -> T::Return {
+        build_indirect_variadic_call(self.builder, function, params, variadic_params)
+    }
+
+    pub fn build_int_cast<T: Integer, U: Integer>(&mut self, from: &Value<T>) -> Value<U> {
+        build_int_cast(self.builder, from)
+    }
+
+    pub fn build_add<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_add(self.builder, lhs, rhs)
+    }
+
+    pub fn build_sub<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_sub(self.builder, lhs, rhs)
+    }
+
+    pub fn build_mul<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_mul(self.builder, lhs, rhs)
+    }
+
+    pub fn build_sdiv<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_sdiv(self.builder, lhs, rhs)
+    }
+
+    pub fn build_exact_sdiv<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_exact_sdiv(self.builder, lhs, rhs)
+    }
+
+    pub fn build_udiv<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_udiv(self.builder, lhs, rhs)
+    }
+
+    pub fn build_exact_udiv<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_exact_udiv(self.builder, lhs, rhs)
+    }
+
+    pub fn build_srem<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_srem(self.builder, lhs, rhs)
+    }
+
+    pub fn build_urem<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_urem(self.builder, lhs, rhs)
+    }
+
+    pub fn build_neg<T: Arithmetic>(&mut self, value: &Value<T>) -> Value<T> {
+        build_neg(self.builder, value)
+    }
+
+    pub fn build_nsw_neg<T: Arithmetic>(&mut self, value: &Value<T>) -> Value<T> {
+        build_nsw_neg(self.builder, value)
+    }
+
+    pub fn build_checked_add<T: Arithmetic>(
+        &mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> (Value<T>, Value<T::Bool>) {
+        build_checked_add(self.builder, lhs, rhs)
+    }
+
+    pub fn build_checked_sub<T: Arithmetic>(
+        &mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> (Value<T>, Value<T::Bool>) {
+        build_checked_sub(self.builder, lhs, rhs)
+    }
+
+    pub fn build_checked_mul<T: Arithmetic>(
+        &mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> (Value<T>, Value<T::Bool>) {
+        build_checked_mul(self.builder, lhs, rhs)
+    }
+
+    pub fn build_saturating_add<T: Arithmetic>(
+        &mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> Value<T> {
+        build_saturating_add(self.builder, lhs, rhs)
+    }
+
+    pub fn build_saturating_sub<T: Arithmetic>(
+        &mut self,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> Value<T> {
+        build_saturating_sub(self.builder, lhs, rhs)
+    }
+
+    pub fn build_and<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_and(self.builder, lhs, rhs)
+    }
+
+    pub fn build_or<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_or(self.builder, lhs, rhs)
+    }
+
+    pub fn build_xor<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_xor(self.builder, lhs, rhs)
+    }
+
+    pub fn build_shl<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_shl(self.builder, lhs, rhs)
+    }
+
+    pub fn build_shr<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_shr(self.builder, lhs, rhs)
+    }
+
+    pub fn build_not<T: Arithmetic>(&mut self, value: &Value<T>) -> Value<T> {
+        build_not(self.builder, value)
+    }
+
+    pub fn build_eq<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_eq(self.builder, lhs, rhs)
+    }
+
+    pub fn build_lt<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_lt(self.builder, lhs, rhs)
+    }
+
+    pub fn build_gt<T: Arithmetic>(&mut self, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+        build_gt(self.builder, lhs, rhs)
+    }
+
+    pub fn build_compare<T: Arithmetic>(
+        &mut self,
+        predicate: Predicate,
+        lhs: &Value<T>,
+        rhs: &Value<T>,
+    ) -> Value<T::Bool> {
+        build_compare(self.builder, predicate, lhs, rhs)
+    }
+
+    pub fn build_ptr_compare<T: ValueType>(
+        &mut self,
+        predicate: Predicate,
+        lhs: &Value<*mut T>,
+        rhs: &Value<*mut T>,
+    ) -> Value<bool> {
+        build_ptr_compare(self.builder, predicate, lhs, rhs)
+    }
+
+    pub fn build_is_null<T: ValueType>(&mut self, ptr: &Value<*mut T>) -> Value<bool> {
+        build_is_null(self.builder, ptr)
+    }
+
+    pub fn build_is_not_null<T: ValueType>(&mut self, ptr: &Value<*mut T>) -> Value<bool> {
+        build_is_not_null(self.builder, ptr)
+    }
+
+    pub fn build_conditional_value<T: Integer, U: ValueType>(
+        &mut self,
+        value: &Value<T>,
+        t: &Value<U>,
+        f: &Value<U>,
+    ) -> Value<U> {
+        build_conditional_value(self.builder, value, t, f)
+    }
+
+    pub fn build_extract_element<T: ValueType, const N: usize, I: Integer>(
+        &mut self,
+        vector: &Value<Vector<T, N>>,
+        index: &Value<I>,
+    ) -> Value<T> {
+        build_extract_element(self.builder, vector, index)
+    }
+
+    pub fn build_insert_element<T: ValueType, const N: usize, I: Integer>(
+        &mut self,
+        vector: &Value<Vector<T, N>>,
+        value: &Value<T>,
+        index: &Value<I>,
+    ) -> Value<Vector<T, N>> {
+        build_insert_element(self.builder, vector, value, index)
+    }
+
+    pub fn build_shuffle<T: ValueType, const N: usize, const M: usize>(
+        &mut self,
+        a: &Value<Vector<T, N>>,
+        b: &Value<Vector<T, N>>,
+        mask: [u32; M],
+    ) -> Value<Vector<T, M>> {
+        build_shuffle(self.builder, a, b, mask)
+    }
+
+    pub fn build_splat<T: ValueType, const N: usize>(
+        &mut self,
+        value: &Value<T>,
+    ) -> Value<Vector<T, N>> {
+        build_splat(self.builder, value)
+    }
+
+    pub fn build_phi<T: ValueType>(&mut self) -> Phi<T> {
+        build_phi(self.builder)
+    }
+
+    pub fn build_load<T: ValueType>(&mut self, ptr: &Value<*mut T>) -> Value<T> {
+        build_load(self.builder, ptr)
+    }
+
+    pub fn build_store<T: ValueType>(&mut self, ptr: &Value<*mut T>, value: &Value<T>) {
+        build_store(self.builder, ptr, value);
+    }
+
+    pub fn build_load_with<T: ValueType>(
+        &mut self,
+        ptr: &Value<*mut T>,
+        flags: AccessFlags,
+    ) -> Value<T> {
+        build_load_with(self.builder, ptr, flags)
+    }
+
+    pub fn build_store_with<T: ValueType>(
+        &mut self,
+        ptr: &Value<*mut T>,
+        value: &Value<T>,
+        flags: AccessFlags,
+    ) {
+        build_store_with(self.builder, ptr, value, flags);
+    }
+
+    pub fn build_atomic_load<T: Atomic>(
+        &mut self,
+        ptr: &Value<*mut T>,
+        ordering: Ordering,
+    ) -> Value<T> {
+        build_atomic_load(self.builder, ptr, ordering)
+    }
+
+    pub fn build_atomic_store<T: Atomic>(
+        &mut self,
+        ptr: &Value<*mut T>,
+        value: &Value<T>,
+        ordering: Ordering,
+    ) {
+        build_atomic_store(self.builder, ptr, value, ordering);
+    }
+
+    pub fn build_atomic_rmw<T: Integer + Atomic>(
+        &mut self,
+        op: AtomicOp,
+        ptr: &Value<*mut T>,
+        value: &Value<T>,
+        ordering: Ordering,
+    ) -> Value<T> {
+        build_atomic_rmw(self.builder, op, ptr, value, ordering)
+    }
+
+    pub fn build_cmpxchg<T: Atomic>(
+        &mut self,
+        ptr: &Value<*mut T>,
+        expected: &Value<T>,
+        new: &Value<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> (Value<T>, Value<bool>) {
+        build_cmpxchg(self.builder, ptr, expected, new, success, failure)
+    }
+
+    pub fn build_fence(&mut self, ordering: Ordering) {
+        build_fence(self.builder, ordering);
+    }
+
+    pub fn build_index_load<T: ValueType, const N: usize, I: Integer>(
+        &mut self,
+        array: &Value<*mut [T; N]>,
+        index: &Value<I>,
+    ) -> Value<T> {
+        build_index_load(self.builder, array, index)
+    }
+
+    pub fn build_index_store<T: ValueType, const N: usize, I: Integer>(
+        &mut self,
+        array: &Value<*mut [T; N]>,
+        index: &Value<I>,
+        value: &Value<T>,
+    ) {
+        build_index_store(self.builder, array, index, value);
+    }
+
+    pub fn build_slice<T: ValueType>(
+        &mut self,
+        ptr: &Value<*mut T>,
+        len: &Value<usize>,
+    ) -> Value<Slice<T>> {
+        build_slice(self.builder, ptr, len)
+    }
+
+    pub fn build_array_slice<T: ValueType, const N: usize>(
+        &mut self,
+        array: &Value<*mut [T; N]>,
+    ) -> Value<Slice<T>> {
+        build_array_slice(self.builder, array)
+    }
+
+    pub fn build_slice_ptr<T: ValueType>(&mut self, slice: &Value<Slice<T>>) -> Value<*mut T> {
+        build_slice_ptr(self.builder, slice)
+    }
+
+    pub fn build_slice_len<T: ValueType>(&mut self, slice: &Value<Slice<T>>) -> Value<usize> {
+        build_slice_len(self.builder, slice)
+    }
+
+    pub fn build_gep<T: ValueType, P: Path<T>>(
+        &mut self,
+        ptr: &Value<*mut T>,
+        path: P,
+    ) -> Value<*mut P::Output> {
+        build_gep(self.builder, ptr, path)
+    }
+
+    pub fn build_extract_field<T: ValueType, const I: usize>(
+        &mut self,
+        aggregate: &Value<T>,
+        _: Field<I>,
+    ) -> Value<<Field<I> as Step<T>>::Output>
+    where
+        Field<I>: Step<T>,
+    {
+        build_extract_value(self.builder, aggregate, I as u32)
+    }
+
+    pub fn build_ptr_offset<T: ValueType, I: Integer>(
+        &mut self,
+        ptr: &Value<*mut T>,
+        index: &Value<I>,
+    ) -> Value<*mut T> {
+        build_ptr_offset(self.builder, ptr, index)
+    }
+
+    pub fn build_ptr_diff<T: ValueType>(
+        &mut self,
+        lhs: &Value<*mut T>,
+        rhs: &Value<*mut T>,
+    ) -> Value<i64> {
+        build_ptr_diff(self.builder, lhs, rhs)
+    }
+
+    pub fn build_ptr_to_int<T: ValueType, I: Integer>(&mut self, ptr: &Value<*mut T>) -> Value<I> {
+        build_ptr_to_int(self.builder, ptr)
+    }
+
+    pub fn build_int_to_ptr<I: Integer, T: ValueType>(
+        &mut self,
+        value: &Value<I>,
+    ) -> Value<*mut T> {
+        build_int_to_ptr(self.builder, value)
+    }
+
+    pub fn build_malloc<T: ValueType>(&mut self, allocator: &Allocator) -> Value<*mut T> {
+        build_malloc(self.builder, allocator)
+    }
+
+    pub fn build_array_malloc<T: ValueType, I: Integer>(
+        &mut self,
+        allocator: &Allocator,
+        count: &Value<I>,
+    ) -> Value<*mut T> {
+        build_array_malloc(self.builder, allocator, count)
+    }
+
+    pub fn build_free<T: ValueType>(&mut self, allocator: &Allocator, ptr: &Value<*mut T>) {
+        build_free(self.builder, allocator, ptr);
+    }
+
+    pub fn build_memset<T: ValueType>(
+        &mut self,
+        ptr: &Value<*mut T>,
+        value: &Value<u8>,
+        flags: MemFlags,
+    ) {
+        let size = build_size_of::<T>();
+        build_memset(self.builder, ptr, value, &size, flags);
+    }
+
+    pub fn build_memcpy<T: ValueType>(
+        &mut self,
+        dst: &Value<*mut T>,
+        src: &Value<*mut T>,
+        flags: MemFlags,
+    ) {
+        let size = build_size_of::<T>();
+        build_memcpy(self.builder, dst, src, &size, flags);
+    }
+
+    pub fn build_memmove<T: ValueType>(
+        &mut self,
+        dst: &Value<*mut T>,
+        src: &Value<*mut T>,
+        flags: MemFlags,
+    ) {
+        let size = build_size_of::<T>();
+        build_memmove(self.builder, dst, src, &size, flags);
+    }
+
+    pub fn build_array_memset<T: ValueType, I: Integer>(
+        &mut self,
+        ptr: &Value<*mut T>,
+        value: &Value<u8>,
+        count: &Value<I>,
+        flags: MemFlags,
+    ) {
+        let size = build_array_size::<T, I>(self.builder, count);
+        build_memset(self.builder, ptr, value, &size, flags);
+    }
+
+    pub fn build_array_memcpy<T: ValueType, I: Integer>(
+        &mut self,
+        dst: &Value<*mut T>,
+        src: &Value<*mut T>,
+        count: &Value<I>,
+        flags: MemFlags,
+    ) {
+        let size = build_array_size::<T, I>(self.builder, count);
+        build_memcpy(self.builder, dst, src, &size, flags);
+    }
+
+    pub fn build_array_memmove<T: ValueType, I: Integer>(
+        &mut self,
+        dst: &Value<*mut T>,
+        src: &Value<*mut T>,
+        count: &Value<I>,
+        flags: MemFlags,
+    ) {
+        let size = build_array_size::<T, I>(self.builder, count);
+        build_memmove(self.builder, dst, src, &size, flags);
+    }
+
+    pub fn build_local<T: ValueType, S: AsRef<str>>(&mut self, name: S) -> Value<*mut T> {
+        build_local(self.builder, name)
+    }
+
+    pub fn build_struct<A: ValueType, B: ValueType>(
+        &mut self,
+        a: &Value<A>,
+        b: &Value<B>,
+    ) -> Value<*mut (A, B)> {
+        build_struct(self.builder, a, b)
+    }
+
+    pub fn build_variant<V: Variant<I>, const I: usize>(
+        &mut self,
+        payload: &Value<V::Payload>,
+    ) -> Value<*mut Enum<V>>
+    where
+        V::Payload: PayloadType,
+    {
+        build_variant::<V, I>(self.builder, payload)
+    }
+
+    pub fn build_unit_variant<V: Variant<I, Payload = ()>, const I: usize>(
+        &mut self,
+    ) -> Value<*mut Enum<V>> {
+        build_unit_variant::<V, I>(self.builder)
+    }
+
+    pub fn build_store_variant<V: Variant<I>, const I: usize>(
+        &mut self,
+        ptr: &Value<*mut Enum<V>>,
+        payload: &Value<V::Payload>,
+    ) where
+        V::Payload: PayloadType,
+    {
+        build_store_variant::<V, I>(self.builder, ptr, payload);
+    }
+
+    pub fn build_store_tag<V: Variant<I>, const I: usize>(&mut self, ptr: &Value<*mut Enum<V>>) {
+        build_store_tag::<V, I>(self.builder, ptr);
+    }
+
+    pub fn build_load_tag<V: Variants>(&mut self, ptr: &Value<*mut Enum<V>>) -> Value<u32> {
+        build_load_tag(self.builder, ptr)
+    }
+
+    pub fn build_exception_ptr(&mut self, exception: &Value<Exception>) -> Value<*mut u8> {
+        build_extract_value(self.builder, exception, 0)
+    }
+
+    pub fn build_exception_selector(&mut self, exception: &Value<Exception>) -> Value<i32> {
+        build_extract_value(self.builder, exception, 1)
+    }
+
+    pub fn build_type_id(&mut self, type_info: &Value<*mut u8>) -> Value<i32> {
+        build_type_id(self.builder, type_info)
+    }
+}
+
+impl Drop for Builder {
+    fn drop(&mut self) {
+        unsafe {
+            LLVMDisposeBuilder(self.builder);
+        }
+    }
+}
+
+fn build_call<T: FunctionType>(
+    builder: *mut LLVMBuilder,
     function: &Function<T>,
     params: T::Params,
 ) -> T::Return {
     function.build_call(builder, params)
 }
 
+fn build_invoke<T: FunctionType>(
+    builder: *mut LLVMBuilder,
+    function: &Function<T>,
+    params: T::Params,
+) -> (T::Return, Block) {
+    let parent = Block::new(unsafe { LLVMGetInsertBlock(builder) }).function();
+    let normal = Block::append(parent, "normal");
+    let unwind = Block::append(parent, "unwind");
+
+    let value = unsafe {
+        let mut params = T::param_values(&params);
+        let name = CString::new("").unwrap();
+
+        let value = LLVMBuildInvoke2(
+            builder,
+            T::function_type(),
+            function.value(),
+            params.as_mut_ptr(),
+            params.len() as u32,
+            normal.value(),
+            unwind.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        );
+
+        LLVMSetInstructionCallConv(value, LLVMGetFunctionCallConv(function.value()));
+        LLVMPositionBuilderAtEnd(builder, normal.value());
+
+        T::return_value(builder, value)
+    };
+
+    (value, unwind)
+}
+
 fn build_variadic_call<T: VariadicFunctionType>(
     builder: *mut LLVMBuilder,
     function: &Function<T>,
@@ -184,6 +1498,49 @@ fn build_variadic_call<T: VariadicFunctionType>(
     function.build_variadic_call(builder, params, variadic_params)
 }
 
+fn build_indirect_call<T: FunctionType + ValueType>(
+    builder: *mut LLVMBuilder,
+    function: &Value<T>,
+    params: T::Params,
+) -> T::Return {
+    T::build_call(builder, function.value(), params)
+}
+
+fn build_call_with<T: FunctionType>(
+    builder: *mut LLVMBuilder,
+    function: *mut LLVMValue,
+    params: T::Params,
+    options: CallOptions,
+) -> T::Return {
+    let call = types::build_call(
+        builder,
+        function,
+        T::function_type(),
+        &T::param_values(&params),
+    );
+
+    unsafe {
+        if let Some(call_conv) = options.call_conv {
+            LLVMSetInstructionCallConv(call, call_conv.into());
+        }
+
+        if options.tail {
+            LLVMSetTailCall(call, 1);
+        }
+    }
+
+    T::return_value(builder, call)
+}
+
+fn build_indirect_variadic_call<T: VariadicFunctionType + ValueType>(
+    builder: *mut LLVMBuilder,
+    function: &Value<T>,
+    params: T::Params,
+    variadic_params: &[UntypedValue],
+) -> T::Return {
+    T::build_variadic_call(builder, function.value(), params, variadic_params)
+}
+
 fn build_int_cast<T: Integer, U: Integer>(builder: *mut LLVMBuilder, from: &Value<T>) -> Value<U> {
     let value = unsafe {
         let name = CString::new("").unwrap();
@@ -199,7 +1556,7 @@ fn build_int_cast<T: Integer, U: Integer>(builder: *mut LLVMBuilder, from: &Valu
     value
 }
 
-fn build_add<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+fn build_add<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
     let value = unsafe {
         let name = CString::new("").unwrap();
 
@@ -214,7 +1571,7 @@ fn build_add<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<
     value
 }
 
-fn build_sub<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+fn build_sub<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
     let value = unsafe {
         let name = CString::new("").unwrap();
 
@@ -229,7 +1586,7 @@ fn build_sub<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<
     value
 }
 
-fn build_mul<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+fn build_mul<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
     let value = unsafe {
         let name = CString::new("").unwrap();
 
@@ -244,7 +1601,11 @@ fn build_mul<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<
     value
 }
 
-fn build_sdiv<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+fn build_sdiv<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> Value<T> {
     let value = unsafe {
         let name = CString::new("").unwrap();
 
@@ -259,7 +1620,346 @@ fn build_sdiv<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value
     value
 }
 
-fn build_eq<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+fn build_exact_sdiv<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildExactSDiv(
+            builder,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_udiv<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildUDiv(
+            builder,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_exact_udiv<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildExactUDiv(
+            builder,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_srem<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildSRem(
+            builder,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_urem<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildURem(
+            builder,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_neg<T: Arithmetic>(builder: *mut LLVMBuilder, value: &Value<T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildNeg(
+            builder,
+            value.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_nsw_neg<T: Arithmetic>(builder: *mut LLVMBuilder, value: &Value<T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildNSWNeg(
+            builder,
+            value.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_checked_add<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> (Value<T>, Value<T::Bool>) {
+    let intrinsic = if T::SIGNED {
+        "llvm.sadd.with.overflow"
+    } else {
+        "llvm.uadd.with.overflow"
+    };
+
+    build_overflow_intrinsic(builder, intrinsic, lhs, rhs)
+}
+
+fn build_checked_sub<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> (Value<T>, Value<T::Bool>) {
+    let intrinsic = if T::SIGNED {
+        "llvm.ssub.with.overflow"
+    } else {
+        "llvm.usub.with.overflow"
+    };
+
+    build_overflow_intrinsic(builder, intrinsic, lhs, rhs)
+}
+
+fn build_checked_mul<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> (Value<T>, Value<T::Bool>) {
+    let intrinsic = if T::SIGNED {
+        "llvm.smul.with.overflow"
+    } else {
+        "llvm.umul.with.overflow"
+    };
+
+    build_overflow_intrinsic(builder, intrinsic, lhs, rhs)
+}
+
+fn build_saturating_add<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> Value<T> {
+    let intrinsic = if T::SIGNED {
+        "llvm.sadd.sat"
+    } else {
+        "llvm.uadd.sat"
+    };
+
+    Value::new(build_intrinsic_call(
+        builder,
+        intrinsic,
+        &[T::value_type()],
+        &[lhs.value(), rhs.value()],
+    ))
+}
+
+fn build_saturating_sub<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> Value<T> {
+    let intrinsic = if T::SIGNED {
+        "llvm.ssub.sat"
+    } else {
+        "llvm.usub.sat"
+    };
+
+    Value::new(build_intrinsic_call(
+        builder,
+        intrinsic,
+        &[T::value_type()],
+        &[lhs.value(), rhs.value()],
+    ))
+}
+
+fn build_overflow_intrinsic<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    intrinsic: &str,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> (Value<T>, Value<T::Bool>) {
+    let result = build_intrinsic_call(
+        builder,
+        intrinsic,
+        &[T::value_type()],
+        &[lhs.value(), rhs.value()],
+    );
+
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildExtractValue(
+            builder,
+            result,
+            0,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    let overflow = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildExtractValue(
+            builder,
+            result,
+            1,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    (value, overflow)
+}
+
+fn build_and<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildAnd(
+            builder,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_or<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildOr(
+            builder,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_xor<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildXor(
+            builder,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_shl<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildShl(
+            builder,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_shr<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(if T::SIGNED {
+            LLVMBuildAShr(
+                builder,
+                lhs.value(),
+                rhs.value(),
+                name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+            )
+        } else {
+            LLVMBuildLShr(
+                builder,
+                lhs.value(),
+                rhs.value(),
+                name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+            )
+        })
+    };
+
+    value
+}
+
+fn build_not<T: Arithmetic>(builder: *mut LLVMBuilder, value: &Value<T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildNot(
+            builder,
+            value.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_eq<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
     let value = unsafe {
         let name = CString::new("").unwrap();
 
@@ -271,10 +1971,777 @@ fn build_eq<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T
             name.to_bytes_with_nul().as_ptr().cast::<i8>(),
         );
 
-        Value::new(LLVMBuildIntCast(
+        Value::new(LLVMBuildIntCast(
+            builder,
+            result,
+            T::value_type(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_lt<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        let result = LLVMBuildICmp(
+            builder,
+            LLVMIntPredicate::LLVMIntSLT,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        );
+
+        Value::new(LLVMBuildIntCast(
+            builder,
+            result,
+            T::value_type(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_gt<T: Arithmetic>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        let result = LLVMBuildICmp(
+            builder,
+            LLVMIntPredicate::LLVMIntSGT,
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        );
+
+        Value::new(LLVMBuildIntCast(
+            builder,
+            result,
+            T::value_type(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_compare<T: Arithmetic>(
+    builder: *mut LLVMBuilder,
+    predicate: Predicate,
+    lhs: &Value<T>,
+    rhs: &Value<T>,
+) -> Value<T::Bool> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildICmp(
+            builder,
+            predicate.into(),
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_ptr_compare<T: ValueType>(
+    builder: *mut LLVMBuilder,
+    predicate: Predicate,
+    lhs: &Value<*mut T>,
+    rhs: &Value<*mut T>,
+) -> Value<bool> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildICmp(
+            builder,
+            predicate.into(),
+            lhs.value(),
+            rhs.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_is_null<T: ValueType>(builder: *mut LLVMBuilder, ptr: &Value<*mut T>) -> Value<bool> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildIsNull(
+            builder,
+            ptr.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_is_not_null<T: ValueType>(builder: *mut LLVMBuilder, ptr: &Value<*mut T>) -> Value<bool> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildIsNotNull(
+            builder,
+            ptr.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_conditional_value<T: Integer, U: ValueType>(
+    builder: *mut LLVMBuilder,
+    value: &Value<T>,
+    t: &Value<U>,
+    f: &Value<U>,
+) -> Value<U> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        let cast = LLVMBuildIntCast(
+            builder,
+            value.value(),
+            LLVMInt1Type(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        );
+
+        Value::new(LLVMBuildSelect(
+            builder,
+            cast,
+            t.value(),
+            f.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_extract_element<T: ValueType, const N: usize, I: Integer>(
+    builder: *mut LLVMBuilder,
+    vector: &Value<Vector<T, N>>,
+    index: &Value<I>,
+) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildExtractElement(
+            builder,
+            vector.value(),
+            index.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_insert_element<T: ValueType, const N: usize, I: Integer>(
+    builder: *mut LLVMBuilder,
+    vector: &Value<Vector<T, N>>,
+    value: &Value<T>,
+    index: &Value<I>,
+) -> Value<Vector<T, N>> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildInsertElement(
+            builder,
+            vector.value(),
+            value.value(),
+            index.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_shuffle<T: ValueType, const N: usize, const M: usize>(
+    builder: *mut LLVMBuilder,
+    a: &Value<Vector<T, N>>,
+    b: &Value<Vector<T, N>>,
+    mask: [u32; M],
+) -> Value<Vector<T, M>> {
+    assert!(
+        mask.iter().all(|&lane| (lane as usize) < 2 * N),
+        "shuffle mask lane out of range for two vectors of {} lanes",
+        N
+    );
+
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+        let mut mask = mask.map(Constant::constant);
+
+        Value::new(LLVMBuildShuffleVector(
+            builder,
+            a.value(),
+            b.value(),
+            LLVMConstVector(mask.as_mut_ptr(), M as u32),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_splat<T: ValueType, const N: usize>(
+    builder: *mut LLVMBuilder,
+    value: &Value<T>,
+) -> Value<Vector<T, N>> {
+    let undef = unsafe { LLVMGetUndef(<Vector<T, N> as ValueType>::value_type()) };
+
+    let first = unsafe {
+        let name = CString::new("").unwrap();
+
+        LLVMBuildInsertElement(
+            builder,
+            undef,
+            value.value(),
+            u32::zero(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        )
+    };
+
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+        let mut mask = [u32::zero(); N];
+
+        Value::new(LLVMBuildShuffleVector(
+            builder,
+            first,
+            undef,
+            LLVMConstVector(mask.as_mut_ptr(), N as u32),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_phi<T: ValueType>(builder: *mut LLVMBuilder) -> Phi<T> {
+    let phi = unsafe {
+        let name = CString::new("").unwrap();
+
+        Phi::new(LLVMBuildPhi(
+            builder,
+            T::value_type(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    phi
+}
+
+fn build_load<T: ValueType>(builder: *mut LLVMBuilder, ptr: &Value<*mut T>) -> Value<T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildLoad2(
+            builder,
+            T::value_type(),
+            ptr.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_store<T: ValueType>(builder: *mut LLVMBuilder, ptr: &Value<*mut T>, value: &Value<T>) {
+    unsafe {
+        LLVMBuildStore(builder, value.value(), ptr.value());
+    }
+}
+
+fn build_load_with<T: ValueType>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+    flags: AccessFlags,
+) -> Value<T> {
+    let value = build_load(builder, ptr);
+    set_access_flags(value.value(), flags);
+    value
+}
+
+fn build_store_with<T: ValueType>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+    value: &Value<T>,
+    flags: AccessFlags,
+) {
+    let store = unsafe { LLVMBuildStore(builder, value.value(), ptr.value()) };
+    set_access_flags(store, flags);
+}
+
+fn set_access_flags(instruction: *mut LLVMValue, flags: AccessFlags) {
+    unsafe {
+        if flags.align != 0 {
+            LLVMSetAlignment(instruction, flags.align);
+        }
+
+        if flags.volatile {
+            LLVMSetVolatile(instruction, 1);
+        }
+
+        if flags.non_temporal {
+            let kind = "nontemporal";
+            let kind = LLVMGetMDKindID(kind.as_ptr().cast::<i8>(), kind.len() as u32);
+            let context = LLVMGetTypeContext(LLVMTypeOf(instruction));
+            let mut one = LLVMValueAsMetadata(LLVMConstInt(LLVMInt32Type(), 1, 0));
+            let node = LLVMMDNodeInContext2(context, &mut one, 1);
+            LLVMSetMetadata(instruction, kind, LLVMMetadataAsValue(context, node));
+        }
+    }
+}
+
+fn build_atomic_load<T: Atomic>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+    ordering: Ordering,
+) -> Value<T> {
+    assert!(
+        !matches!(ordering, Ordering::Release | Ordering::AcqRel),
+        "there is no such thing as a release atomic load"
+    );
+
+    let value = build_load(builder, ptr);
+
+    unsafe {
+        LLVMSetOrdering(value.value(), ordering.into());
+    }
+
+    value
+}
+
+fn build_atomic_store<T: Atomic>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+    value: &Value<T>,
+    ordering: Ordering,
+) {
+    assert!(
+        !matches!(ordering, Ordering::Acquire | Ordering::AcqRel),
+        "there is no such thing as an acquire atomic store"
+    );
+
+    unsafe {
+        let store = LLVMBuildStore(builder, value.value(), ptr.value());
+        LLVMSetOrdering(store, ordering.into());
+    }
+}
+
+fn build_atomic_rmw<T: Integer + Atomic>(
+    builder: *mut LLVMBuilder,
+    op: AtomicOp,
+    ptr: &Value<*mut T>,
+    value: &Value<T>,
+    ordering: Ordering,
+) -> Value<T> {
+    unsafe {
+        Value::new(LLVMBuildAtomicRMW(
+            builder,
+            op.binop(T::SIGNED),
+            ptr.value(),
+            value.value(),
+            ordering.into(),
+            0,
+        ))
+    }
+}
+
+fn build_cmpxchg<T: Atomic>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+    expected: &Value<T>,
+    new: &Value<T>,
+    success: Ordering,
+    failure: Ordering,
+) -> (Value<T>, Value<bool>) {
+    assert!(
+        !matches!(failure, Ordering::Release | Ordering::AcqRel),
+        "there is no such thing as a release failure ordering"
+    );
+
+    let result = unsafe {
+        LLVMBuildAtomicCmpXchg(
+            builder,
+            ptr.value(),
+            expected.value(),
+            new.value(),
+            success.into(),
+            failure.into(),
+            0,
+        )
+    };
+
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildExtractValue(
+            builder,
+            result,
+            0,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    let success = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildExtractValue(
+            builder,
+            result,
+            1,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    (value, success)
+}
+
+fn build_fence(builder: *mut LLVMBuilder, ordering: Ordering) {
+    assert!(
+        ordering != Ordering::Relaxed,
+        "there is no such thing as a relaxed fence"
+    );
+
+    unsafe {
+        let name = CString::new("").unwrap();
+        LLVMBuildFence(
+            builder,
+            ordering.into(),
+            0,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        );
+    }
+}
+
+fn build_index_load<T: ValueType, const N: usize, I: Integer>(
+    builder: *mut LLVMBuilder,
+    array: &Value<*mut [T; N]>,
+    index: &Value<I>,
+) -> Value<T> {
+    let ep = unsafe {
+        let name = CString::new("").unwrap();
+        let mut indices = [I::zero(), index.value()];
+
+        LLVMBuildGEP2(
+            builder,
+            <[T; N] as ValueType>::value_type(),
+            array.value(),
+            indices.as_mut_ptr(),
+            2,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        )
+    };
+
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildLoad2(
+            builder,
+            T::value_type(),
+            ep,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_index_store<T: ValueType, const N: usize, I: Integer>(
+    builder: *mut LLVMBuilder,
+    array: &Value<*mut [T; N]>,
+    index: &Value<I>,
+    value: &Value<T>,
+) {
+    let ep = unsafe {
+        let name = CString::new("").unwrap();
+        let mut indices = [I::zero(), index.value()];
+
+        LLVMBuildGEP2(
+            builder,
+            <[T; N] as ValueType>::value_type(),
+            array.value(),
+            indices.as_mut_ptr(),
+            2,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        )
+    };
+
+    unsafe {
+        LLVMBuildStore(builder, value.value(), ep);
+    }
+}
+
+fn build_slice<T: ValueType>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+    len: &Value<usize>,
+) -> Value<Slice<T>> {
+    let first = unsafe {
+        let name = CString::new("").unwrap();
+
+        LLVMBuildInsertValue(
+            builder,
+            LLVMGetUndef(<Slice<T> as ValueType>::value_type()),
+            ptr.value(),
+            0,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        )
+    };
+
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildInsertValue(
+            builder,
+            first,
+            len.value(),
+            1,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_array_slice<T: ValueType, const N: usize>(
+    builder: *mut LLVMBuilder,
+    array: &Value<*mut [T; N]>,
+) -> Value<Slice<T>> {
+    let ptr = unsafe {
+        let name = CString::new("").unwrap();
+        let mut indices = [usize::zero(), usize::zero()];
+
+        Value::new(LLVMBuildGEP2(
+            builder,
+            <[T; N] as ValueType>::value_type(),
+            array.value(),
+            indices.as_mut_ptr(),
+            2,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    build_slice(builder, &ptr, &Value::constant(N))
+}
+
+fn build_slice_ptr<T: ValueType>(
+    builder: *mut LLVMBuilder,
+    slice: &Value<Slice<T>>,
+) -> Value<*mut T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildExtractValue(
+            builder,
+            slice.value(),
+            0,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_slice_len<T: ValueType>(
+    builder: *mut LLVMBuilder,
+    slice: &Value<Slice<T>>,
+) -> Value<usize> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildExtractValue(
+            builder,
+            slice.value(),
+            1,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_slice_index_load<T: ValueType, I: Integer>(
+    builder: *mut LLVMBuilder,
+    slice: &Value<Slice<T>>,
+    index: &Value<I>,
+    out_of_bounds: OutOfBounds,
+) -> Value<T> {
+    let ep = build_bounds_checked_ep(builder, slice, index, out_of_bounds);
+
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildLoad2(
+            builder,
+            T::value_type(),
+            ep,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_slice_index_store<T: ValueType, I: Integer>(
+    builder: *mut LLVMBuilder,
+    slice: &Value<Slice<T>>,
+    index: &Value<I>,
+    value: &Value<T>,
+    out_of_bounds: OutOfBounds,
+) {
+    let ep = build_bounds_checked_ep(builder, slice, index, out_of_bounds);
+
+    unsafe {
+        LLVMBuildStore(builder, value.value(), ep);
+    }
+}
+
+// The trap block is kept directly after the entry block, so it can be found without scanning
+// the whole function on every bounds check
+fn trap_block(function: *mut LLVMValue) -> Block {
+    let entry = unsafe { LLVMGetEntryBasicBlock(function) };
+    let existing = unsafe { LLVMGetNextBasicBlock(entry) };
+
+    if !existing.is_null() && is_trap_block(existing) {
+        return Block::new(existing);
+    }
+
+    let block = Block::append(function, "out_of_bounds");
+    let trap = block.build();
+    build_intrinsic_call(trap.builder, "llvm.trap", &[], &[]);
+    trap.build_unreachable();
+
+    unsafe {
+        LLVMMoveBasicBlockAfter(block.value(), entry);
+    }
+
+    block
+}
+
+fn is_trap_block(block: *mut LLVMBasicBlock) -> bool {
+    unsafe {
+        let call = LLVMGetFirstInstruction(block);
+
+        if LLVMIsACallInst(call).is_null()
+            || LLVMIsAUnreachableInst(LLVMGetNextInstruction(call)).is_null()
+        {
+            return false;
+        }
+
+        let mut length = 0;
+        let callee = LLVMGetValueName2(LLVMGetCalledValue(call), &mut length);
+        std::slice::from_raw_parts(callee.cast::<u8>(), length) == b"llvm.trap"
+    }
+}
+
+// Leaves the builder at the end of a new block which is only reached when the index is in bounds
+fn build_bounds_checked_ep<T: ValueType, I: Integer>(
+    builder: *mut LLVMBuilder,
+    slice: &Value<Slice<T>>,
+    index: &Value<I>,
+    out_of_bounds: OutOfBounds,
+) -> *mut LLVMValue {
+    let function = Block::new(unsafe { LLVMGetInsertBlock(builder) }).function();
+    let ptr = build_slice_ptr(builder, slice);
+    let len = build_slice_len(builder, slice);
+
+    let index = unsafe {
+        let name = CString::new("").unwrap();
+
+        LLVMBuildIntCast2(
+            builder,
+            index.value(),
+            usize::value_type(),
+            if I::SIGNED { 1 } else { 0 },
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        )
+    };
+
+    let in_bounds = Block::append(function, "in_bounds");
+    let out_of_bounds = match out_of_bounds {
+        OutOfBounds::Branch(block) => block,
+        OutOfBounds::Trap => trap_block(function),
+    };
+
+    unsafe {
+        let name = CString::new("").unwrap();
+        let cmp = LLVMBuildICmp(
+            builder,
+            LLVMIntPredicate::LLVMIntULT,
+            index,
+            len.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        );
+        LLVMBuildCondBr(builder, cmp, in_bounds.value(), out_of_bounds.value());
+        LLVMPositionBuilderAtEnd(builder, in_bounds.value());
+    }
+
+    unsafe {
+        let name = CString::new("").unwrap();
+        let mut indices = [index];
+
+        LLVMBuildGEP2(
+            builder,
+            T::value_type(),
+            ptr.value(),
+            indices.as_mut_ptr(),
+            1,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        )
+    }
+}
+
+fn build_gep<T: ValueType, P: Path<T>>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+    path: P,
+) -> Value<*mut P::Output> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+        let mut indices = vec![u32::zero()];
+        indices.extend(path.indices());
+
+        Value::new(LLVMBuildGEP2(
+            builder,
+            T::value_type(),
+            ptr.value(),
+            indices.as_mut_ptr(),
+            indices.len() as u32,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_ptr_offset<T: ValueType, I: Integer>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+    index: &Value<I>,
+) -> Value<*mut T> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+        let mut indices = [index.value()];
+
+        Value::new(LLVMBuildGEP2(
             builder,
-            result,
             T::value_type(),
+            ptr.value(),
+            indices.as_mut_ptr(),
+            1,
             name.to_bytes_with_nul().as_ptr().cast::<i8>(),
         ))
     };
@@ -282,45 +2749,37 @@ fn build_eq<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T
     value
 }
 
-fn build_lt<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+fn build_ptr_diff<T: ValueType>(
+    builder: *mut LLVMBuilder,
+    lhs: &Value<*mut T>,
+    rhs: &Value<*mut T>,
+) -> Value<i64> {
     let value = unsafe {
         let name = CString::new("").unwrap();
 
-        let result = LLVMBuildICmp(
+        Value::new(LLVMBuildPtrDiff2(
             builder,
-            LLVMIntPredicate::LLVMIntSLT,
+            T::value_type(),
             lhs.value(),
             rhs.value(),
             name.to_bytes_with_nul().as_ptr().cast::<i8>(),
-        );
-
-        Value::new(LLVMBuildIntCast(
-            builder,
-            result,
-            T::value_type(),
-            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
         ))
     };
 
     value
 }
 
-fn build_gt<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T>) -> Value<T> {
+fn build_ptr_to_int<T: ValueType, I: Integer>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+) -> Value<I> {
     let value = unsafe {
         let name = CString::new("").unwrap();
 
-        let result = LLVMBuildICmp(
-            builder,
-            LLVMIntPredicate::LLVMIntSGT,
-            lhs.value(),
-            rhs.value(),
-            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
-        );
-
-        Value::new(LLVMBuildIntCast(
+        Value::new(LLVMBuildPtrToInt(
             builder,
-            result,
-            T::value_type(),
+            ptr.value(),
+            I::value_type(),
             name.to_bytes_with_nul().as_ptr().cast::<i8>(),
         ))
     };
@@ -328,27 +2787,17 @@ fn build_gt<T: Integer>(builder: *mut LLVMBuilder, lhs: &Value<T>, rhs: &Value<T
     value
 }
 
-fn build_conditional_value<T: Integer, U: ValueType>(
+fn build_int_to_ptr<I: Integer, T: ValueType>(
     builder: *mut LLVMBuilder,
-    value: &Value<T>,
-    t: &Value<U>,
-    f: &Value<U>,
-) -> Value<U> {
+    value: &Value<I>,
+) -> Value<*mut T> {
     let value = unsafe {
         let name = CString::new("").unwrap();
 
-        let cast = LLVMBuildIntCast(
+        Value::new(LLVMBuildIntToPtr(
             builder,
             value.value(),
-            LLVMInt1Type(),
-            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
-        );
-
-        Value::new(LLVMBuildSelect(
-            builder,
-            cast,
-            t.value(),
-            f.value(),
+            <*mut T>::value_type(),
             name.to_bytes_with_nul().as_ptr().cast::<i8>(),
         ))
     };
@@ -356,53 +2805,132 @@ fn build_conditional_value<T: Integer, U: ValueType>(
     value
 }
 
-fn build_load<T: ValueType>(builder: *mut LLVMBuilder, ptr: &Value<*mut T>) -> Value<T> {
-    let value = unsafe {
+fn build_malloc<T: ValueType>(builder: *mut LLVMBuilder, allocator: &Allocator) -> Value<*mut T> {
+    let size = build_size_of::<T>();
+    let ptr = build_call(builder, &allocator.malloc(builder), (size,));
+
+    build_ptr_cast(builder, &ptr)
+}
+
+fn build_array_malloc<T: ValueType, I: Integer>(
+    builder: *mut LLVMBuilder,
+    allocator: &Allocator,
+    count: &Value<I>,
+) -> Value<*mut T> {
+    let size = build_array_size::<T, I>(builder, count);
+    let ptr = build_call(builder, &allocator.malloc(builder), (size,));
+
+    build_ptr_cast(builder, &ptr)
+}
+
+fn build_free<T: ValueType>(builder: *mut LLVMBuilder, allocator: &Allocator, ptr: &Value<*mut T>) {
+    let ptr = build_ptr_cast(builder, ptr);
+    build_call(builder, &allocator.free(builder), (ptr,));
+}
+
+fn build_array_size<T: ValueType, I: Integer>(
+    builder: *mut LLVMBuilder,
+    count: &Value<I>,
+) -> Value<usize> {
+    let count = unsafe {
         let name = CString::new("").unwrap();
 
-        Value::new(LLVMBuildLoad2(
+        Value::new(LLVMBuildIntCast2(
             builder,
-            T::value_type(),
-            ptr.value(),
+            count.value(),
+            usize::value_type(),
+            if I::SIGNED { 1 } else { 0 },
             name.to_bytes_with_nul().as_ptr().cast::<i8>(),
         ))
     };
 
-    value
+    build_mul(builder, &build_size_of::<T>(), &count)
 }
 
-fn build_store<T: ValueType>(builder: *mut LLVMBuilder, ptr: &Value<*mut T>, value: &Value<T>) {
+fn build_size_of<T: ValueType>() -> Value<usize> {
+    Value::new(unsafe { LLVMSizeOf(T::value_type()) })
+}
+
+fn build_memset<T: ValueType>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+    value: &Value<u8>,
+    size: &Value<usize>,
+    flags: MemFlags,
+) {
     unsafe {
-        LLVMBuildStore(builder, value.value(), ptr.value());
+        let call = LLVMBuildMemSet(
+            builder,
+            ptr.value(),
+            value.value(),
+            size.value(),
+            flags.align,
+        );
+        set_mem_intrinsic_volatile(call, flags);
     }
 }
 
-fn build_index_load<T: ValueType, const N: usize, I: Integer>(
+fn build_memcpy<T: ValueType>(
     builder: *mut LLVMBuilder,
-    array: &Value<*mut [T; N]>,
-    index: &Value<I>,
-) -> Value<T> {
-    let ep = unsafe {
-        let name = CString::new("").unwrap();
-        let mut indices = [I::zero(), index.value()];
+    dst: &Value<*mut T>,
+    src: &Value<*mut T>,
+    size: &Value<usize>,
+    flags: MemFlags,
+) {
+    unsafe {
+        let call = LLVMBuildMemCpy(
+            builder,
+            dst.value(),
+            flags.align,
+            src.value(),
+            flags.align,
+            size.value(),
+        );
+        set_mem_intrinsic_volatile(call, flags);
+    }
+}
 
-        LLVMBuildGEP2(
+fn build_memmove<T: ValueType>(
+    builder: *mut LLVMBuilder,
+    dst: &Value<*mut T>,
+    src: &Value<*mut T>,
+    size: &Value<usize>,
+    flags: MemFlags,
+) {
+    unsafe {
+        let call = LLVMBuildMemMove(
             builder,
-            <[T; N] as ValueType>::value_type(),
-            array.value(),
-            indices.as_mut_ptr(),
-            2,
-            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
-        )
-    };
+            dst.value(),
+            flags.align,
+            src.value(),
+            flags.align,
+            size.value(),
+        );
+        set_mem_intrinsic_volatile(call, flags);
+    }
+}
+
+// The C API always builds non-volatile calls, but the flag is the last operand of every
+// memory intrinsic
+fn set_mem_intrinsic_volatile(call: *mut LLVMValue, flags: MemFlags) {
+    unsafe {
+        if flags.volatile {
+            LLVMSetOperand(call, 3, true.constant());
+        }
+    }
+}
 
+fn build_ptr_cast<T: ValueType, U: ValueType>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut T>,
+) -> Value<*mut U> {
     let value = unsafe {
         let name = CString::new("").unwrap();
 
-        Value::new(LLVMBuildLoad2(
+        Value::new(LLVMBuildPointerCast(
             builder,
-            T::value_type(),
-            ep,
+            ptr.value(),
+            <*mut U>::value_type(),
             name.to_bytes_with_nul().as_ptr().cast::<i8>(),
         ))
     };
@@ -410,28 +2938,38 @@ fn build_index_load<T: ValueType, const N: usize, I: Integer>(
     value
 }
 
-fn build_index_store<T: ValueType, const N: usize, I: Integer>(
+fn build_local<T: ValueType, S: AsRef<str>>(builder: *mut LLVMBuilder, name: S) -> Value<*mut T> {
+    Value::new(build_entry_alloca(builder, T::value_type(), name))
+}
+
+// Allocas in the entry block are only executed once per call and can be promoted by mem2reg
+fn build_entry_alloca<S: AsRef<str>>(
     builder: *mut LLVMBuilder,
-    array: &Value<*mut [T; N]>,
-    index: &Value<I>,
-    value: &Value<T>,
-) {
-    let ep = unsafe {
-        let name = CString::new("").unwrap();
-        let mut indices = [I::zero(), index.value()];
+    ty: *mut LLVMType,
+    name: S,
+) -> *mut LLVMValue {
+    let entry =
+        unsafe { LLVMGetEntryBasicBlock(LLVMGetBasicBlockParent(LLVMGetInsertBlock(builder))) };
+    let alloca_builder = Builder::new();
 
-        LLVMBuildGEP2(
-            builder,
-            <[T; N] as ValueType>::value_type(),
-            array.value(),
-            indices.as_mut_ptr(),
-            2,
-            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
-        )
-    };
+    unsafe {
+        let first = LLVMGetFirstInstruction(entry);
+
+        if first.is_null() {
+            LLVMPositionBuilderAtEnd(alloca_builder.builder, entry);
+        } else {
+            LLVMPositionBuilderBefore(alloca_builder.builder, first);
+        }
+    }
 
     unsafe {
-        LLVMBuildStore(builder, value.value(), ep);
+        let name = CString::new(name.as_ref()).unwrap();
+
+        LLVMBuildAlloca(
+            alloca_builder.builder,
+            ty,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        )
     }
 }
 
@@ -440,15 +2978,7 @@ fn build_struct<A: ValueType, B: ValueType>(
     a: &Value<A>,
     b: &Value<B>,
 ) -> Value<*mut (A, B)> {
-    let value = unsafe {
-        let name = CString::new("").unwrap();
-
-        LLVMBuildAlloca(
-            builder,
-            <(A, B) as ValueType>::value_type(),
-            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
-        )
-    };
+    let value = build_entry_alloca(builder, <(A, B) as ValueType>::value_type(), "");
 
     let first_ep = unsafe {
         let name = CString::new("").unwrap();
@@ -482,12 +3012,240 @@ fn build_struct<A: ValueType, B: ValueType>(
     Value::new(value)
 }
 
+fn build_variant<V: Variant<I>, const I: usize>(
+    builder: *mut LLVMBuilder,
+    payload: &Value<V::Payload>,
+) -> Value<*mut Enum<V>>
+where
+    V::Payload: PayloadType,
+{
+    let value = Value::new(build_entry_alloca(
+        builder,
+        <Enum<V> as ValueType>::value_type(),
+        "",
+    ));
+
+    build_store_variant::<V, I>(builder, &value, payload);
+    value
+}
+
+fn build_unit_variant<V: Variant<I, Payload = ()>, const I: usize>(
+    builder: *mut LLVMBuilder,
+) -> Value<*mut Enum<V>> {
+    let value = Value::new(build_entry_alloca(
+        builder,
+        <Enum<V> as ValueType>::value_type(),
+        "",
+    ));
+
+    build_store_tag::<V, I>(builder, &value);
+    value
+}
+
+fn build_store_variant<V: Variant<I>, const I: usize>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut Enum<V>>,
+    payload: &Value<V::Payload>,
+) where
+    V::Payload: PayloadType,
+{
+    build_store_tag::<V, I>(builder, ptr);
+    build_store(builder, &build_payload_ep::<V, I>(builder, ptr), payload);
+}
+
+fn build_store_tag<V: Variant<I>, const I: usize>(
+    builder: *mut LLVMBuilder,
+    ptr: &Value<*mut Enum<V>>,
+) {
+    unsafe {
+        LLVMBuildStore(builder, (I as u32).constant(), build_tag_ep(builder, ptr));
+    }
+}
+
+fn build_load_tag<V: Variants>(builder: *mut LLVMBuilder, ptr: &Value<*mut Enum<V>>) -> Value<u32> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildLoad2(
+            builder,
+            u32::value_type(),
+            build_tag_ep(builder, ptr),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+// Control flow helpers take values as true when they are non-zero, so the targets given to
+// build_conditional_jump are swapped
+
+fn build_if<C, T, F, G>(builder: *mut LLVMBuilder, cond: &Value<C>, then: F, else_: G) -> Value<T>
+where
+    C: Integer,
+    T: ValueType,
+    F: FnOnce(Builder) -> (Value<T>, Builder),
+    G: FnOnce(Builder) -> (Value<T>, Builder),
+{
+    let function = Block::new(unsafe { LLVMGetInsertBlock(builder) }).function();
+    let then_block = Block::append(function, "then");
+    let else_block = Block::append(function, "else");
+    let (merge, (value,)) = ParamBlock::<(T,)>::append(function, "merge");
+
+    build_conditional_jump(builder, cond, &else_block, &then_block);
+
+    let (then_value, then_builder) = then(then_block.build());
+    then_builder.build_jump_with_params(&merge, (then_value,));
+
+    let (else_value, else_builder) = else_(else_block.build());
+    else_builder.build_jump_with_params(&merge, (else_value,));
+
+    unsafe {
+        LLVMPositionBuilderAtEnd(builder, merge.block().value());
+    }
+
+    value
+}
+
+fn build_if_then<C, F>(builder: *mut LLVMBuilder, cond: &Value<C>, then: F)
+where
+    C: Integer,
+    F: FnOnce(Builder) -> Builder,
+{
+    let function = Block::new(unsafe { LLVMGetInsertBlock(builder) }).function();
+    let then_block = Block::append(function, "then");
+    let merge = Block::append(function, "merge");
+
+    build_conditional_jump(builder, cond, &merge, &then_block);
+    then(then_block.build()).build_jump(&merge);
+
+    unsafe {
+        LLVMPositionBuilderAtEnd(builder, merge.value());
+    }
+}
+
+fn build_while<C, F, G>(builder: *mut LLVMBuilder, cond: F, body: G)
+where
+    C: Integer,
+    F: FnOnce(Builder) -> (Value<C>, Builder),
+    G: FnOnce(Builder) -> Builder,
+{
+    let function = Block::new(unsafe { LLVMGetInsertBlock(builder) }).function();
+    let header = Block::append(function, "while_header");
+    let body_block = Block::append(function, "while_body");
+    let exit = Block::append(function, "while_exit");
+
+    build_jump(builder, &header);
+
+    let (value, header_builder) = cond(header.build());
+    header_builder.build_conditional_jump(&value, &exit, &body_block);
+
+    body(body_block.build()).build_jump(&header);
+
+    unsafe {
+        LLVMPositionBuilderAtEnd(builder, exit.value());
+    }
+}
+
+fn build_for<T, F>(builder: *mut LLVMBuilder, range: Range<Value<T>>, body: F)
+where
+    T: Integer,
+    F: FnOnce(Builder, Value<T>) -> Builder,
+{
+    let function = Block::new(unsafe { LLVMGetInsertBlock(builder) }).function();
+    let (header, (index,)) = ParamBlock::<(T,)>::append(function, "for_header");
+    let body_block = Block::append(function, "for_body");
+    let exit = Block::append(function, "for_exit");
+
+    build_jump_with_params(builder, &header, (range.start,));
+
+    unsafe {
+        let header_builder = header.build();
+        let name = CString::new("").unwrap();
+
+        let cmp = LLVMBuildICmp(
+            header_builder.builder,
+            if T::SIGNED {
+                LLVMIntPredicate::LLVMIntSLT
+            } else {
+                LLVMIntPredicate::LLVMIntULT
+            },
+            index.value(),
+            range.end.value(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        );
+        LLVMBuildCondBr(
+            header_builder.builder,
+            cmp,
+            body_block.value(),
+            exit.value(),
+        );
+    }
+
+    let body_builder = body(body_block.build(), index);
+    let one = Value::new(unsafe { LLVMConstInt(T::value_type(), 1, 0) });
+    let next = build_add(body_builder.builder, &index, &one);
+    body_builder.build_jump_with_params(&header, (next,));
+
+    unsafe {
+        LLVMPositionBuilderAtEnd(builder, exit.value());
+    }
+}
+
+fn build_extract_value<T: ValueType, U: ValueType>(
+    builder: *mut LLVMBuilder,
+    aggregate: &Value<T>,
+    index: u32,
+) -> Value<U> {
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildExtractValue(
+            builder,
+            aggregate.value(),
+            index,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+fn build_type_id(builder: *mut LLVMBuilder, type_info: &Value<*mut u8>) -> Value<i32> {
+    Value::new(build_intrinsic_call(
+        builder,
+        "llvm.eh.typeid.for",
+        &[],
+        &[type_info.value()],
+    ))
+}
+
+fn build_resume(builder: *mut LLVMBuilder, exception: &Value<Exception>) {
+    unsafe {
+        LLVMBuildResume(builder, exception.value());
+    }
+}
+
 fn build_unreachable(builder: *mut LLVMBuilder) {
     unsafe {
         LLVMBuildUnreachable(builder);
     }
 }
 
+fn build_indirect_branch(
+    builder: *mut LLVMBuilder,
+    address: &Value<BlockAddress>,
+    destinations: &[&Block],
+) {
+    unsafe {
+        let branch = LLVMBuildIndirectBr(builder, address.value(), destinations.len() as u32);
+
+        for destination in destinations {
+            LLVMAddDestination(branch, destination.value());
+        }
+    }
+}
+
 fn build_jump(builder: *mut LLVMBuilder, block: &Block) {
     unsafe {
         LLVMBuildBr(builder, block.value());
@@ -514,6 +3272,44 @@ fn build_conditional_jump<T: Integer>(
     }
 }
 
+fn build_jump_with_params<P: BlockParams>(
+    builder: *mut LLVMBuilder,
+    block: &ParamBlock<P>,
+    params: P::Values,
+) {
+    unsafe {
+        block.add_incoming(LLVMGetInsertBlock(builder), &params);
+    }
+
+    build_jump(builder, &block.block());
+}
+
+fn build_conditional_jump_with_params<T: Integer, P: BlockParams, Q: BlockParams>(
+    builder: *mut LLVMBuilder,
+    value: &Value<T>,
+    t: &ParamBlock<P>,
+    t_params: P::Values,
+    f: &ParamBlock<Q>,
+    f_params: Q::Values,
+) {
+    let current = unsafe { LLVMGetInsertBlock(builder) };
+    t.add_incoming(current, &t_params);
+
+    // A phi can only have one incoming value per predecessor, so a second edge into the same
+    // block has to come from a block of its own
+    let f_block = if t.block().value() == f.block().value() {
+        let edge = Block::append(Block::new(current).function(), "jump_params");
+        f.add_incoming(edge.value(), &f_params);
+        build_jump(edge.build().builder, &f.block());
+        edge
+    } else {
+        f.add_incoming(current, &f_params);
+        f.block()
+    };
+
+    build_conditional_jump(builder, value, &t.block(), &f_block);
+}
+
 fn build_ret<T: ValueType>(builder: *mut LLVMBuilder, value: &Value<T>) {
     unsafe {
         LLVMBuildRet(builder, value.value());
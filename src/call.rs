@@ -0,0 +1,34 @@
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CallConv {
+    C,
+    Fast,
+    Cold,
+    Ghc,
+    HiPE,
+    PreserveMost,
+    PreserveAll,
+    Swift,
+    Tail,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallOptions {
+    pub call_conv: Option<CallConv>,
+    pub tail: bool,
+}
+
+impl From<CallConv> for u32 {
+    fn from(call_conv: CallConv) -> Self {
+        match call_conv {
+            CallConv::C => 0,
+            CallConv::Fast => 8,
+            CallConv::Cold => 9,
+            CallConv::Ghc => 10,
+            CallConv::HiPE => 11,
+            CallConv::PreserveMost => 14,
+            CallConv::PreserveAll => 15,
+            CallConv::Swift => 16,
+            CallConv::Tail => 18,
+        }
+    }
+}
@@ -1,10 +1,16 @@
 use std::marker::PhantomData;
 
-use llvm_sys::{core::LLVMAppendBasicBlock, LLVMBuilder, LLVMValue};
+use llvm_sys::{
+    core::{LLVMSetFunctionCallConv, LLVMSetPersonalityFn},
+    LLVMBuilder, LLVMValue,
+};
 
-use crate::{value::UntypedValue, Block, FunctionType, Value, ValueType, VariadicFunctionType};
-
-use std::ffi::CString;
+use crate::{
+    block::{BlockParams, ParamBlock},
+    call::CallConv,
+    value::UntypedValue,
+    Block, FunctionType, Value, ValueType, VariadicFunctionType,
+};
 
 #[derive(Copy, Clone)]
 pub struct Function<T: FunctionType> {
@@ -36,18 +42,35 @@ impl<T: FunctionType> Function<T> {
         T::build_variadic_call(builder, self.value, params, variadic_params)
     }
 
+    pub(crate) fn value(&self) -> *mut LLVMValue {
+        self.value
+    }
+
     pub fn params(&self) -> T::Params {
         T::function_params(self.value)
     }
 
     pub fn add_block<S: AsRef<str>>(&self, name: S) -> Block {
-        let name = CString::new(name.as_ref()).unwrap();
+        Block::append(self.value, name)
+    }
+
+    pub fn add_block_with_params<P: BlockParams, S: AsRef<str>>(
+        &self,
+        name: S,
+    ) -> (ParamBlock<P>, P::Values) {
+        ParamBlock::append(self.value, name)
+    }
 
-        let block = unsafe {
-            LLVMAppendBasicBlock(self.value, name.to_bytes_with_nul().as_ptr().cast::<i8>())
-        };
+    pub fn set_call_conv(&self, call_conv: CallConv) {
+        unsafe {
+            LLVMSetFunctionCallConv(self.value, call_conv.into());
+        }
+    }
 
-        Block::new(block)
+    pub fn set_personality<P: FunctionType>(&self, personality: &Function<P>) {
+        unsafe {
+            LLVMSetPersonalityFn(self.value, personality.value);
+        }
     }
 
     pub fn as_value(&self) -> Value<T>
@@ -0,0 +1,94 @@
+use llvm_sys::LLVMValue;
+
+use crate::{
+    value::{Constant, Integer},
+    Value, ValueType,
+};
+
+pub struct Field<const I: usize>;
+
+pub trait Step<T: ValueType> {
+    type Output: ValueType;
+
+    fn index(&self) -> *mut LLVMValue;
+}
+
+pub trait Path<T: ValueType> {
+    type Output: ValueType;
+
+    fn indices(&self) -> Vec<*mut LLVMValue>;
+}
+
+impl<T: ValueType, const N: usize, I: Integer> Step<[T; N]> for Value<I> {
+    type Output = T;
+
+    fn index(&self) -> *mut LLVMValue {
+        self.value()
+    }
+}
+
+impl<A: ValueType, B: ValueType> Step<(A, B)> for Field<0> {
+    type Output = A;
+
+    fn index(&self) -> *mut LLVMValue {
+        0u32.constant()
+    }
+}
+
+impl<A: ValueType, B: ValueType> Step<(A, B)> for Field<1> {
+    type Output = B;
+
+    fn index(&self) -> *mut LLVMValue {
+        1u32.constant()
+    }
+}
+
+impl<T: ValueType, S0: Step<T>> Path<T> for (S0,) {
+    type Output = S0::Output;
+
+    fn indices(&self) -> Vec<*mut LLVMValue> {
+        vec![self.0.index()]
+    }
+}
+
+impl<T: ValueType, S0: Step<T>, S1: Step<S0::Output>> Path<T> for (S0, S1) {
+    type Output = S1::Output;
+
+    fn indices(&self) -> Vec<*mut LLVMValue> {
+        vec![self.0.index(), self.1.index()]
+    }
+}
+
+impl<T, S0, S1, S2> Path<T> for (S0, S1, S2)
+where
+    T: ValueType,
+    S0: Step<T>,
+    S1: Step<S0::Output>,
+    S2: Step<S1::Output>,
+{
+    type Output = S2::Output;
+
+    fn indices(&self) -> Vec<*mut LLVMValue> {
+        vec![self.0.index(), self.1.index(), self.2.index()]
+    }
+}
+
+impl<T, S0, S1, S2, S3> Path<T> for (S0, S1, S2, S3)
+where
+    T: ValueType,
+    S0: Step<T>,
+    S1: Step<S0::Output>,
+    S2: Step<S1::Output>,
+    S3: Step<S2::Output>,
+{
+    type Output = S3::Output;
+
+    fn indices(&self) -> Vec<*mut LLVMValue> {
+        vec![
+            self.0.index(),
+            self.1.index(),
+            self.2.index(),
+            self.3.index(),
+        ]
+    }
+}
@@ -0,0 +1,149 @@
+use std::{ffi::CString, marker::PhantomData};
+
+use llvm_sys::{
+    core::{
+        LLVMBuildCall2, LLVMGetBasicBlockParent, LLVMGetGlobalContext, LLVMGetGlobalParent,
+        LLVMGetInsertBlock, LLVMGetIntrinsicDeclaration, LLVMIntrinsicGetType,
+        LLVMLookupIntrinsicID,
+    },
+    LLVMBuilder, LLVMModule, LLVMType, LLVMValue,
+};
+
+use crate::{
+    value::{Arithmetic, Float, MultiByte},
+    FunctionType, Value, ValueType,
+};
+
+pub trait Intrinsic {
+    type Type: FunctionType;
+
+    const NAME: &'static str;
+
+    fn overloads() -> Vec<*mut LLVMType>;
+}
+
+#[derive(Copy, Clone)]
+pub struct WithFlag<F, const FLAG: bool>(PhantomData<F>);
+
+impl<T, R, const FLAG: bool> FunctionType for WithFlag<fn(T) -> R, FLAG>
+where
+    T: ValueType,
+    R: ValueType,
+{
+    type Params = (Value<T>,);
+    type Return = R::ReturnType;
+
+    fn function_type() -> *mut LLVMType {
+        <fn(T, bool) -> R>::function_type()
+    }
+
+    fn function_params(function: *mut LLVMValue) -> Self::Params {
+        <fn(T) -> R>::function_params(function)
+    }
+
+    fn param_values(params: &Self::Params) -> Vec<*mut LLVMValue> {
+        vec![params.0.value(), Value::constant(FLAG).value()]
+    }
+
+    fn return_value(builder: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::Return {
+        R::as_return_value(builder, value)
+    }
+}
+
+macro_rules! intrinsic {
+    ($name:ident<T: $bound:ident> = $intrinsic:literal: $f:ty) => {
+        pub struct $name<T>(PhantomData<T>);
+
+        impl<T: $bound> Intrinsic for $name<T> {
+            type Type = $f;
+
+            const NAME: &'static str = $intrinsic;
+
+            fn overloads() -> Vec<*mut LLVMType> {
+                vec![T::value_type()]
+            }
+        }
+    };
+
+    ($name:ident<T: $bound:ident, const $flag:ident: bool> = $intrinsic:literal: $f:ty) => {
+        pub struct $name<T, const $flag: bool>(PhantomData<T>);
+
+        impl<T: $bound, const $flag: bool> Intrinsic for $name<T, $flag> {
+            type Type = WithFlag<$f, $flag>;
+
+            const NAME: &'static str = $intrinsic;
+
+            fn overloads() -> Vec<*mut LLVMType> {
+                vec![T::value_type()]
+            }
+        }
+    };
+}
+
+intrinsic!(Ctpop<T: Arithmetic> = "llvm.ctpop": fn(T) -> T);
+intrinsic!(Ctlz<T: Arithmetic, const ZERO_UNDEF: bool> = "llvm.ctlz": fn(T) -> T);
+intrinsic!(Cttz<T: Arithmetic, const ZERO_UNDEF: bool> = "llvm.cttz": fn(T) -> T);
+intrinsic!(Bswap<T: MultiByte> = "llvm.bswap": fn(T) -> T);
+intrinsic!(Bitreverse<T: Arithmetic> = "llvm.bitreverse": fn(T) -> T);
+intrinsic!(Abs<T: Arithmetic, const INT_MIN_POISON: bool> = "llvm.abs": fn(T) -> T);
+intrinsic!(Smin<T: Arithmetic> = "llvm.smin": fn(T, T) -> T);
+intrinsic!(Smax<T: Arithmetic> = "llvm.smax": fn(T, T) -> T);
+intrinsic!(Umin<T: Arithmetic> = "llvm.umin": fn(T, T) -> T);
+intrinsic!(Umax<T: Arithmetic> = "llvm.umax": fn(T, T) -> T);
+intrinsic!(Fshl<T: Arithmetic> = "llvm.fshl": fn(T, T, T) -> T);
+intrinsic!(Fshr<T: Arithmetic> = "llvm.fshr": fn(T, T, T) -> T);
+intrinsic!(Sqrt<T: Float> = "llvm.sqrt": fn(T) -> T);
+intrinsic!(Fma<T: Float> = "llvm.fma": fn(T, T, T) -> T);
+intrinsic!(Fabs<T: Float> = "llvm.fabs": fn(T) -> T);
+intrinsic!(Floor<T: Float> = "llvm.floor": fn(T) -> T);
+intrinsic!(Ceil<T: Float> = "llvm.ceil": fn(T) -> T);
+intrinsic!(Trunc<T: Float> = "llvm.trunc": fn(T) -> T);
+intrinsic!(Round<T: Float> = "llvm.round": fn(T) -> T);
+intrinsic!(Minnum<T: Float> = "llvm.minnum": fn(T, T) -> T);
+intrinsic!(Maxnum<T: Float> = "llvm.maxnum": fn(T, T) -> T);
+intrinsic!(Pow<T: Float> = "llvm.pow": fn(T, T) -> T);
+intrinsic!(Copysign<T: Float> = "llvm.copysign": fn(T, T) -> T);
+
+pub(crate) fn intrinsic_declaration(
+    module: *mut LLVMModule,
+    intrinsic: &str,
+    overloads: &[*mut LLVMType],
+) -> *mut LLVMValue {
+    unsafe {
+        let id = LLVMLookupIntrinsicID(intrinsic.as_ptr().cast::<i8>(), intrinsic.len());
+
+        LLVMGetIntrinsicDeclaration(module, id, overloads.as_ptr() as *mut _, overloads.len())
+    }
+}
+
+pub(crate) fn build_intrinsic_call(
+    builder: *mut LLVMBuilder,
+    intrinsic: &str,
+    overloads: &[*mut LLVMType],
+    params: &[*mut LLVMValue],
+) -> *mut LLVMValue {
+    unsafe {
+        let module = LLVMGetGlobalParent(LLVMGetBasicBlockParent(LLVMGetInsertBlock(builder)));
+        let id = LLVMLookupIntrinsicID(intrinsic.as_ptr().cast::<i8>(), intrinsic.len());
+
+        let function =
+            LLVMGetIntrinsicDeclaration(module, id, overloads.as_ptr() as *mut _, overloads.len());
+
+        let function_type = LLVMIntrinsicGetType(
+            LLVMGetGlobalContext(),
+            id,
+            overloads.as_ptr() as *mut _,
+            overloads.len(),
+        );
+
+        let name = CString::new("").unwrap();
+        LLVMBuildCall2(
+            builder,
+            function_type,
+            function,
+            params.as_ptr() as *mut _,
+            params.len() as u32,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        )
+    }
+}
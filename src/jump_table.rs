@@ -1,11 +1,22 @@
-use std::marker::PhantomData;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    ops::RangeInclusive,
+};
 
 use llvm_sys::{
-    core::{LLVMAddCase, LLVMBuildSwitch},
-    LLVMBasicBlock, LLVMValue,
+    core::{LLVMAddCase, LLVMBuildSwitch, LLVMGetNumOperands, LLVMGetOperand, LLVMIsAConstantInt},
+    LLVMBasicBlock, LLVMBuilder, LLVMValue,
 };
 
-use crate::{Block, Builder, Value, ValueType};
+use crate::{value::Constant, Block, Builder, Value, ValueType};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JumpTableError {
+    NonConstant,
+    Duplicate,
+}
 
 struct Case {
     value: *mut LLVMValue,
@@ -21,6 +32,22 @@ pub struct JumpTable<T: ValueType> {
     phantom: PhantomData<T>,
 }
 
+pub struct Switch<T: ValueType> {
+    switch: *mut LLVMValue,
+    phantom: PhantomData<T>,
+}
+
+impl Display for JumpTableError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            JumpTableError::NonConstant => write!(f, "jump table case is not a constant integer"),
+            JumpTableError::Duplicate => write!(f, "jump table case is already present"),
+        }
+    }
+}
+
+impl Error for JumpTableError {}
+
 impl Case {
     pub fn new(value: *mut LLVMValue, block: &Block) -> Self {
         Self {
@@ -41,13 +68,47 @@ impl<T: ValueType> JumpTable<T> {
         }
     }
 
-    pub fn case(mut self, value: &Value<T>, block: &Block) -> Self {
+    pub(crate) fn builder(&self) -> *mut LLVMBuilder {
+        self.builder.builder
+    }
+
+    pub fn case(&mut self, value: &Value<T>, block: &Block) -> Result<&mut Self, JumpTableError> {
+        check_case(value.value(), self.cases.iter().map(|case| case.value))?;
+
         self.cases.push(Case::new(value.value(), block));
-        self
+        Ok(self)
     }
 
-    pub fn finish(self) {
-        unsafe {
+    pub fn case_const(&mut self, value: T, block: &Block) -> Result<&mut Self, JumpTableError>
+    where
+        T: Constant,
+    {
+        self.case(&Value::constant(value), block)
+    }
+
+    pub fn case_range(
+        &mut self,
+        range: RangeInclusive<T>,
+        block: &Block,
+    ) -> Result<&mut Self, JumpTableError>
+    where
+        T: Constant,
+        RangeInclusive<T>: Iterator<Item = T>,
+    {
+        let values = range.map(Constant::constant).collect::<Vec<_>>();
+
+        for value in &values {
+            check_case(*value, self.cases.iter().map(|case| case.value))?;
+        }
+
+        self.cases
+            .extend(values.into_iter().map(|value| Case::new(value, block)));
+
+        Ok(self)
+    }
+
+    pub fn finish(self) -> Switch<T> {
+        let switch = unsafe {
             let switch = LLVMBuildSwitch(
                 self.builder.builder,
                 self.value,
@@ -58,6 +119,85 @@ impl<T: ValueType> JumpTable<T> {
             for Case { value, block } in self.cases {
                 LLVMAddCase(switch, value, block);
             }
+
+            switch
+        };
+
+        Switch {
+            switch,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: ValueType> Clone for Switch<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ValueType> Copy for Switch<T> {}
+
+impl<T: ValueType> Switch<T> {
+    fn cases(&self) -> Vec<*mut LLVMValue> {
+        unsafe {
+            let operands = LLVMGetNumOperands(self.switch) as u32;
+
+            (2..operands)
+                .step_by(2)
+                .map(|index| LLVMGetOperand(self.switch, index))
+                .collect()
+        }
+    }
+
+    pub fn case(&self, value: &Value<T>, block: &Block) -> Result<(), JumpTableError> {
+        check_case(value.value(), self.cases().into_iter())?;
+
+        unsafe {
+            LLVMAddCase(self.switch, value.value(), block.value());
+        }
+
+        Ok(())
+    }
+
+    pub fn case_const(&self, value: T, block: &Block) -> Result<(), JumpTableError>
+    where
+        T: Constant,
+    {
+        self.case(&Value::constant(value), block)
+    }
+
+    pub fn case_range(&self, range: RangeInclusive<T>, block: &Block) -> Result<(), JumpTableError>
+    where
+        T: Constant,
+        RangeInclusive<T>: Iterator<Item = T>,
+    {
+        let cases = self.cases();
+        let values = range.map(Constant::constant).collect::<Vec<_>>();
+
+        for value in &values {
+            check_case(*value, cases.iter().copied())?;
+        }
+
+        for value in values {
+            unsafe {
+                LLVMAddCase(self.switch, value, block.value());
+            }
         }
+
+        Ok(())
+    }
+}
+
+fn check_case(
+    value: *mut LLVMValue,
+    mut cases: impl Iterator<Item = *mut LLVMValue>,
+) -> Result<(), JumpTableError> {
+    if unsafe { LLVMIsAConstantInt(value) }.is_null() {
+        Err(JumpTableError::NonConstant)
+    } else if cases.any(|case| case == value) {
+        Err(JumpTableError::Duplicate)
+    } else {
+        Ok(())
     }
 }
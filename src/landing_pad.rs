@@ -0,0 +1,70 @@
+use std::ffi::CString;
+
+use llvm_sys::{
+    core::{
+        LLVMAddClause, LLVMBuildLandingPad, LLVMConstPointerNull, LLVMInt8Type, LLVMPointerType,
+        LLVMSetCleanup,
+    },
+    LLVMValue,
+};
+
+use crate::{Builder, Exception, Value, ValueType};
+
+#[must_use]
+pub struct LandingPad {
+    builder: Builder,
+    clauses: Vec<*mut LLVMValue>,
+    cleanup: bool,
+}
+
+impl LandingPad {
+    pub(crate) fn new(builder: Builder) -> Self {
+        Self {
+            builder,
+            clauses: Vec::new(),
+            cleanup: false,
+        }
+    }
+
+    pub fn catch(mut self, type_info: &Value<*mut u8>) -> Self {
+        self.clauses.push(type_info.value());
+        self
+    }
+
+    pub fn catch_all(mut self) -> Self {
+        let null = unsafe { LLVMConstPointerNull(LLVMPointerType(LLVMInt8Type(), 0)) };
+        self.clauses.push(null);
+        self
+    }
+
+    pub fn cleanup(mut self) -> Self {
+        self.cleanup = true;
+        self
+    }
+
+    pub fn finish(self) -> (Value<Exception>, Builder) {
+        let value = unsafe {
+            let name = CString::new("").unwrap();
+
+            let landing_pad = LLVMBuildLandingPad(
+                self.builder.builder,
+                Exception::value_type(),
+                std::ptr::null_mut(),
+                self.clauses.len() as u32,
+                name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+            );
+
+            for clause in self.clauses {
+                LLVMAddClause(landing_pad, clause);
+            }
+
+            if self.cleanup {
+                LLVMSetCleanup(landing_pad, 1);
+            }
+
+            Value::new(landing_pad)
+        };
+
+        (value, self.builder)
+    }
+}
@@ -1,14 +1,36 @@
+mod allocator;
+mod atomic;
 mod block;
 mod builder;
+mod call;
 mod function;
+mod gep;
+mod intrinsic;
 mod jump_table;
+mod landing_pad;
+mod memory;
 mod module;
+mod phi;
+mod tagged_union;
 mod types;
 mod value;
 
-pub use self::block::Block;
-pub use self::builder::Builder;
+pub use self::allocator::Allocator;
+pub use self::atomic::{Atomic, AtomicOp, Ordering};
+pub use self::block::{Block, BlockParams, ParamBlock};
+pub use self::builder::{Builder, BuilderRef, OutOfBounds, Predicate};
+pub use self::call::{CallConv, CallOptions};
 pub use self::function::Function;
+pub use self::gep::{Field, Path, Step};
+pub use self::intrinsic::*;
+pub use self::jump_table::{JumpTableError, Switch};
+pub use self::landing_pad::LandingPad;
+pub use self::memory::{AccessFlags, MemFlags};
 pub use self::module::Module;
+pub use self::phi::Phi;
+pub use self::tagged_union::{Enum, Match, PayloadType, Variant, Variants};
 pub use self::types::*;
-pub use self::value::{UntypedValue, Value};
+pub use self::value::{Constant, UntypedValue, Value};
+
+#[doc(hidden)]
+pub use llvm_sys as __llvm_sys;
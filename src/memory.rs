@@ -0,0 +1,12 @@
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemFlags {
+    pub align: u32,
+    pub volatile: bool,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccessFlags {
+    pub align: u32,
+    pub volatile: bool,
+    pub non_temporal: bool,
+}
@@ -8,13 +8,14 @@ use llvm_sys::{
     core::{
         LLVMAddFunction, LLVMAddGlobal, LLVMArrayType, LLVMConstArray, LLVMConstBitCast,
         LLVMConstString, LLVMDisposeMessage, LLVMDisposeModule, LLVMInt8Type,
-        LLVMModuleCreateWithName, LLVMPrintModuleToString, LLVMSetGlobalConstant,
+        LLVMModuleCreateWithName, LLVMPointerType, LLVMPrintModuleToString, LLVMSetGlobalConstant,
         LLVMSetInitializer, LLVMSetLinkage, LLVMSetSourceFileName,
     },
-    LLVMLinkage, LLVMModule,
+    LLVMLinkage, LLVMModule, LLVMValue,
 };
 
 use crate::{
+    intrinsic::{intrinsic_declaration, Intrinsic},
     types::ValueType,
     value::{Constant, Integer},
     Function, FunctionType, Value,
@@ -70,6 +71,26 @@ impl Module {
         Function::new(function)
     }
 
+    pub fn add_intrinsic<T: Intrinsic>(&self) -> Function<T::Type> {
+        Function::new(intrinsic_declaration(self.module, T::NAME, &T::overloads()))
+    }
+
+    pub fn add_type_info<S: AsRef<str>>(&self, name: S) -> Value<*mut u8> {
+        let name = CString::new(name.as_ref()).unwrap();
+
+        unsafe {
+            let global = LLVMAddGlobal(
+                self.module,
+                LLVMPointerType(LLVMInt8Type(), 0),
+                name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+            );
+
+            LLVMSetGlobalConstant(global, 1);
+
+            Value::new(LLVMConstBitCast(global, LLVMPointerType(LLVMInt8Type(), 0)))
+        }
+    }
+
     pub fn add_string<S: AsRef<str>>(&self, string: S) -> Value<String> {
         let cstring = CString::new(string.as_ref()).unwrap();
         let bytes = cstring.to_bytes_with_nul();
@@ -98,6 +119,23 @@ impl Module {
     }
 
     pub fn add_array<T: Integer, const N: usize>(&self) -> Value<*mut [T; N]> {
+        let mut vals = [T::zero(); N];
+        let value = unsafe { LLVMConstArray(T::value_type(), vals.as_mut_ptr(), N as u32) };
+
+        self.add_array_global(value)
+    }
+
+    pub fn add_array_with<T: Constant, const N: usize>(
+        &self,
+        values: [T; N],
+    ) -> Value<*mut [T; N]> {
+        self.add_array_global(values.constant())
+    }
+
+    fn add_array_global<T: ValueType, const N: usize>(
+        &self,
+        value: *mut LLVMValue,
+    ) -> Value<*mut [T; N]> {
         let global = {
             let name = CString::new("array").unwrap();
 
@@ -113,9 +151,6 @@ impl Module {
         unsafe {
             LLVMSetLinkage(global, LLVMLinkage::LLVMInternalLinkage);
             LLVMSetGlobalConstant(global, 0);
-
-            let mut vals = [T::zero(); N];
-            let value = LLVMConstArray(T::value_type(), vals.as_mut_ptr(), N as u32);
             LLVMSetInitializer(global, value);
 
             Value::new(global)
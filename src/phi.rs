@@ -0,0 +1,39 @@
+use std::marker::PhantomData;
+
+use llvm_sys::{core::LLVMAddIncoming, LLVMValue};
+
+use crate::{Block, Value, ValueType};
+
+pub struct Phi<T: ValueType> {
+    value: *mut LLVMValue,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ValueType> Clone for Phi<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ValueType> Copy for Phi<T> {}
+
+impl<T: ValueType> Phi<T> {
+    pub(crate) fn new(value: *mut LLVMValue) -> Self {
+        Self {
+            value,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn add_incoming(&self, value: &Value<T>, block: &Block) {
+        unsafe {
+            let mut values = [value.value()];
+            let mut blocks = [block.value()];
+            LLVMAddIncoming(self.value, values.as_mut_ptr(), blocks.as_mut_ptr(), 1);
+        }
+    }
+
+    pub fn as_value(&self) -> Value<T> {
+        Value::new(self.value)
+    }
+}
@@ -0,0 +1,226 @@
+use std::{ffi::CString, marker::PhantomData};
+
+use llvm_sys::{
+    core::{
+        LLVMArrayType, LLVMBuildBitCast, LLVMBuildStructGEP2, LLVMGetTypeKind, LLVMIntType,
+        LLVMStructType,
+    },
+    target::{
+        LLVMABIAlignmentOfType, LLVMABISizeOfType, LLVMCreateTargetData, LLVMDisposeTargetData,
+    },
+    LLVMBuilder, LLVMType, LLVMTypeKind, LLVMValue,
+};
+
+use crate::{
+    jump_table::{JumpTable, JumpTableError},
+    Block, BlockAddress, Exception, Slice, Value, ValueType, Variadic, Vector,
+};
+
+pub struct Enum<V>(PhantomData<V>);
+
+pub trait Variants {
+    fn payload_types() -> Vec<*mut LLVMType>;
+}
+
+pub trait Variant<const I: usize>: Variants {
+    type Payload: ValueType;
+}
+
+pub trait PayloadType: ValueType {}
+
+macro_rules! payload_type {
+    ($($t:ty),*) => {
+        $(impl PayloadType for $t {})*
+    };
+}
+
+payload_type!(
+    bool,
+    i8,
+    u8,
+    i32,
+    i64,
+    u32,
+    u64,
+    usize,
+    f32,
+    f64,
+    String,
+    BlockAddress,
+    Exception
+);
+
+impl<T: ValueType> PayloadType for *mut T {}
+impl<T: ValueType, const N: usize> PayloadType for [T; N] {}
+impl<T: ValueType, const N: usize> PayloadType for Vector<T, N> {}
+impl<T: ValueType> PayloadType for Slice<T> {}
+impl<A: ValueType, B: ValueType> PayloadType for (A, B) {}
+impl<V: Variants> PayloadType for Enum<V> {}
+impl<R: ValueType> PayloadType for fn() -> R {}
+impl<T: ValueType, R: ValueType> PayloadType for fn(T) -> R {}
+impl<T: ValueType, U: ValueType, R: ValueType> PayloadType for fn(T, U) -> R {}
+impl<T: ValueType, U: ValueType, V: ValueType, R: ValueType> PayloadType for fn(T, U, V) -> R {}
+impl<T: ValueType, R: ValueType> PayloadType for fn(T, Variadic) -> R {}
+
+macro_rules! variants {
+    ($($t:ident),*) => {
+        impl<$($t: ValueType),*> Variants for ($($t,)*) {
+            fn payload_types() -> Vec<*mut LLVMType> {
+                vec![$($t::value_type()),*]
+            }
+        }
+    };
+}
+
+macro_rules! variant {
+    ($i:literal => $p:ident in $($t:ident),*) => {
+        impl<$($t: ValueType),*> Variant<$i> for ($($t,)*) {
+            type Payload = $p;
+        }
+    };
+}
+
+variants!(A);
+variants!(A, B);
+variants!(A, B, C);
+variants!(A, B, C, D);
+
+variant!(0 => A in A);
+variant!(0 => A in A, B);
+variant!(1 => B in A, B);
+variant!(0 => A in A, B, C);
+variant!(1 => B in A, B, C);
+variant!(2 => C in A, B, C);
+variant!(0 => A in A, B, C, D);
+variant!(1 => B in A, B, C, D);
+variant!(2 => C in A, B, C, D);
+variant!(3 => D in A, B, C, D);
+
+impl<V: Variants> ValueType for Enum<V> {
+    type ReturnType = Value<Enum<V>>;
+
+    fn value_type() -> *mut LLVMType {
+        unsafe {
+            let mut types = [u32::value_type(), payload_type::<V>()];
+            LLVMStructType(types.as_mut_ptr(), 2, 0)
+        }
+    }
+
+    fn as_return_value(_: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::ReturnType {
+        Value::new(value)
+    }
+}
+
+// Modules are created without a data layout, so the default layout is the one they use
+fn payload_type<V: Variants>() -> *mut LLVMType {
+    unsafe {
+        let layout = CString::new("").unwrap();
+        let data = LLVMCreateTargetData(layout.to_bytes_with_nul().as_ptr().cast::<i8>());
+
+        let (size, align) = V::payload_types()
+            .into_iter()
+            .filter(|ty| LLVMGetTypeKind(*ty) != LLVMTypeKind::LLVMVoidTypeKind)
+            .fold((0, 1), |(size, align), ty| {
+                (
+                    u64::max(size, LLVMABISizeOfType(data, ty)),
+                    u32::max(align, LLVMABIAlignmentOfType(data, ty)),
+                )
+            });
+
+        LLVMDisposeTargetData(data);
+
+        LLVMArrayType(
+            LLVMIntType(align * 8),
+            size.div_ceil(u64::from(align)) as u32,
+        )
+    }
+}
+
+pub(crate) fn build_tag_ep<V: Variants>(
+    builder: *mut LLVMBuilder,
+    value: &Value<*mut Enum<V>>,
+) -> *mut LLVMValue {
+    unsafe {
+        let name = CString::new("").unwrap();
+
+        LLVMBuildStructGEP2(
+            builder,
+            <Enum<V> as ValueType>::value_type(),
+            value.value(),
+            0,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        )
+    }
+}
+
+pub(crate) fn build_payload_ep<V: Variant<I>, const I: usize>(
+    builder: *mut LLVMBuilder,
+    value: &Value<*mut Enum<V>>,
+) -> Value<*mut V::Payload>
+where
+    V::Payload: PayloadType,
+{
+    let ep = unsafe {
+        let name = CString::new("").unwrap();
+
+        LLVMBuildStructGEP2(
+            builder,
+            <Enum<V> as ValueType>::value_type(),
+            value.value(),
+            1,
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        )
+    };
+
+    let value = unsafe {
+        let name = CString::new("").unwrap();
+
+        Value::new(LLVMBuildBitCast(
+            builder,
+            ep,
+            <*mut V::Payload>::value_type(),
+            name.to_bytes_with_nul().as_ptr().cast::<i8>(),
+        ))
+    };
+
+    value
+}
+
+#[must_use]
+pub struct Match<V: Variants> {
+    table: JumpTable<u32>,
+    value: Value<*mut Enum<V>>,
+}
+
+impl<V: Variants> Match<V> {
+    pub(crate) fn new(table: JumpTable<u32>, value: &Value<*mut Enum<V>>) -> Self {
+        Self {
+            table,
+            value: *value,
+        }
+    }
+
+    pub fn arm<const I: usize>(
+        &mut self,
+        block: &Block,
+    ) -> Result<Value<*mut V::Payload>, JumpTableError>
+    where
+        V: Variant<I>,
+        V::Payload: PayloadType,
+    {
+        self.table.case_const(I as u32, block)?;
+        Ok(build_payload_ep::<V, I>(self.table.builder(), &self.value))
+    }
+
+    pub fn case<const I: usize>(&mut self, block: &Block) -> Result<&mut Self, JumpTableError>
+    where
+        V: Variant<I>,
+    {
+        self.table.case_const(I as u32, block)?;
+        Ok(self)
+    }
+
+    pub fn finish(self) {
+        self.table.finish();
+    }
+}
@@ -1,11 +1,13 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
-use std::ffi::CString;
+use std::{ffi::CString, marker::PhantomData};
 
 use llvm_sys::{
     core::{
-        LLVMArrayType, LLVMBuildCall2, LLVMBuildExtractValue, LLVMFunctionType, LLVMGetParam,
-        LLVMInt32Type, LLVMInt64Type, LLVMInt8Type, LLVMPointerType, LLVMStructType, LLVMVoidType,
+        LLVMArrayType, LLVMBuildCall2, LLVMBuildExtractValue, LLVMDoubleType, LLVMFloatType,
+        LLVMFunctionType, LLVMGetFunctionCallConv, LLVMGetParam, LLVMInt1Type, LLVMInt32Type,
+        LLVMInt64Type, LLVMInt8Type, LLVMIsAFunction, LLVMPointerType, LLVMSetInstructionCallConv,
+        LLVMStructType, LLVMVectorType, LLVMVoidType,
     },
     LLVMBuilder, LLVMType, LLVMValue,
 };
@@ -14,6 +16,14 @@ use crate::{value::UntypedValue, Function, Value};
 
 pub struct Variadic;
 
+pub struct Vector<T, const N: usize>(pub [T; N]);
+
+pub struct Slice<T>(PhantomData<T>);
+
+pub struct BlockAddress;
+
+pub struct Exception;
+
 pub trait ValueType {
     type ReturnType;
 
@@ -29,11 +39,25 @@ pub trait FunctionType {
 
     fn function_params(function: *mut LLVMValue) -> Self::Params;
 
+    fn param_values(params: &Self::Params) -> Vec<*mut LLVMValue>;
+
+    fn return_value(builder: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::Return;
+
     fn build_call(
         builder: *mut LLVMBuilder,
         function: *mut LLVMValue,
         params: Self::Params,
-    ) -> Self::Return;
+    ) -> Self::Return {
+        Self::return_value(
+            builder,
+            build_call(
+                builder,
+                function,
+                Self::function_type(),
+                &Self::param_values(&params),
+            ),
+        )
+    }
 }
 
 pub trait VariadicFunctionType: FunctionType {
@@ -61,11 +85,22 @@ macro_rules! value_type {
     };
 }
 
+value_type!(bool => LLVMInt1Type());
+value_type!(i8 => LLVMInt8Type());
+value_type!(u8 => LLVMInt8Type());
 value_type!(i32 => LLVMInt32Type());
 value_type!(i64 => LLVMInt64Type());
 value_type!(u32 => LLVMInt32Type());
 value_type!(u64 => LLVMInt32Type());
+value_type!(usize => LLVMInt64Type());
+value_type!(f32 => LLVMFloatType());
+value_type!(f64 => LLVMDoubleType());
 value_type!(String => LLVMPointerType(LLVMInt8Type(), 0));
+value_type!(BlockAddress => LLVMPointerType(LLVMInt8Type(), 0));
+value_type!(Exception => {
+    let mut types = [LLVMPointerType(LLVMInt8Type(), 0), LLVMInt32Type()];
+    LLVMStructType(types.as_mut_ptr(), 2, 0)
+});
 
 impl ValueType for () {
     type ReturnType = ();
@@ -101,18 +136,55 @@ impl<T: ValueType, const N: usize> ValueType for [T; N] {
     }
 }
 
-impl<R: ValueType> ValueType for fn() -> R {
-    type ReturnType = Function<fn() -> R>;
+impl<T: ValueType, const N: usize> ValueType for Vector<T, N> {
+    type ReturnType = Value<Vector<T, N>>;
+
+    fn value_type() -> *mut LLVMType {
+        unsafe { LLVMVectorType(T::value_type(), N as u32) }
+    }
+
+    fn as_return_value(_: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::ReturnType {
+        Value::new(value)
+    }
+}
+
+impl<T: ValueType> ValueType for Slice<T> {
+    type ReturnType = Value<Slice<T>>;
 
     fn value_type() -> *mut LLVMType {
-        unsafe { LLVMPointerType(<fn() -> R as FunctionType>::function_type(), 0) }
+        unsafe {
+            let mut types = [<*mut T>::value_type(), usize::value_type()];
+            LLVMStructType(types.as_mut_ptr(), 2, 0)
+        }
     }
 
     fn as_return_value(_: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::ReturnType {
-        Function::new(value)
+        Value::new(value)
     }
 }
 
+macro_rules! function_value_type {
+    ($($t:ident),* => $f:ty) => {
+        impl<$($t: ValueType),*> ValueType for $f {
+            type ReturnType = Function<$f>;
+
+            fn value_type() -> *mut LLVMType {
+                unsafe { LLVMPointerType(<$f as FunctionType>::function_type(), 0) }
+            }
+
+            fn as_return_value(_: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::ReturnType {
+                Function::new(value)
+            }
+        }
+    };
+}
+
+function_value_type!(R => fn() -> R);
+function_value_type!(T, R => fn(T) -> R);
+function_value_type!(T, U, R => fn(T, U) -> R);
+function_value_type!(T, U, V, R => fn(T, U, V) -> R);
+function_value_type!(T, R => fn(T, Variadic) -> R);
+
 impl<A: ValueType, B: ValueType> ValueType for (A, B) {
     type ReturnType = (A::ReturnType, B::ReturnType);
 
@@ -164,7 +236,7 @@ fn function_type(ret: *mut LLVMType, params: &[*mut LLVMType], variadic: bool) -
     }
 }
 
-fn build_call(
+pub(crate) fn build_call(
     builder: *mut LLVMBuilder,
     function: *mut LLVMValue,
     function_type: *mut LLVMType,
@@ -172,14 +244,20 @@ fn build_call(
 ) -> *mut LLVMValue {
     unsafe {
         let name = CString::new("").unwrap();
-        LLVMBuildCall2(
+        let call = LLVMBuildCall2(
             builder,
             function_type,
             function,
             params.as_ptr() as *mut _,
             params.len() as u32,
             name.to_bytes_with_nul().as_ptr().cast::<i8>(),
-        )
+        );
+
+        if !LLVMIsAFunction(function).is_null() {
+            LLVMSetInstructionCallConv(call, LLVMGetFunctionCallConv(function));
+        }
+
+        call
     }
 }
 
@@ -196,15 +274,12 @@ where
 
     fn function_params(_: *mut LLVMValue) -> Self::Params {}
 
-    fn build_call(
-        builder: *mut LLVMBuilder,
-        function: *mut LLVMValue,
-        _: Self::Params,
-    ) -> Self::Return {
-        R::as_return_value(
-            builder,
-            build_call(builder, function, Self::function_type(), &[]),
-        )
+    fn param_values(_: &Self::Params) -> Vec<*mut LLVMValue> {
+        Vec::new()
+    }
+
+    fn return_value(builder: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::Return {
+        R::as_return_value(builder, value)
     }
 }
 
@@ -224,20 +299,12 @@ where
         unsafe { (Value::new(LLVMGetParam(function, 0)),) }
     }
 
-    fn build_call(
-        builder: *mut LLVMBuilder,
-        function: *mut LLVMValue,
-        params: Self::Params,
-    ) -> Self::Return {
-        R::as_return_value(
-            builder,
-            build_call(
-                builder,
-                function,
-                Self::function_type(),
-                &[params.0.value()],
-            ),
-        )
+    fn param_values(params: &Self::Params) -> Vec<*mut LLVMValue> {
+        vec![params.0.value()]
+    }
+
+    fn return_value(builder: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::Return {
+        R::as_return_value(builder, value)
     }
 }
 
@@ -263,21 +330,50 @@ where
         }
     }
 
-    fn build_call(
-        builder: *mut LLVMBuilder,
-        function: *mut LLVMValue,
-        params: Self::Params,
-    ) -> Self::Return {
-        R::as_return_value(
-            builder,
-            build_call(
-                builder,
-                function,
-                Self::function_type(),
-                &[params.0.value(), params.1.value()],
-            ),
+    fn param_values(params: &Self::Params) -> Vec<*mut LLVMValue> {
+        vec![params.0.value(), params.1.value()]
+    }
+
+    fn return_value(builder: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::Return {
+        R::as_return_value(builder, value)
+    }
+}
+
+impl<T, U, V, R> FunctionType for fn(T, U, V) -> R
+where
+    T: ValueType,
+    U: ValueType,
+    V: ValueType,
+    R: ValueType,
+{
+    type Params = (Value<T>, Value<U>, Value<V>);
+    type Return = R::ReturnType;
+
+    fn function_type() -> *mut LLVMType {
+        function_type(
+            R::value_type(),
+            &[T::value_type(), U::value_type(), V::value_type()],
+            false,
         )
     }
+
+    fn function_params(function: *mut LLVMValue) -> Self::Params {
+        unsafe {
+            (
+                Value::new(LLVMGetParam(function, 0)),
+                Value::new(LLVMGetParam(function, 1)),
+                Value::new(LLVMGetParam(function, 2)),
+            )
+        }
+    }
+
+    fn param_values(params: &Self::Params) -> Vec<*mut LLVMValue> {
+        vec![params.0.value(), params.1.value(), params.2.value()]
+    }
+
+    fn return_value(builder: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::Return {
+        R::as_return_value(builder, value)
+    }
 }
 
 impl<T, R> FunctionType for fn(T, Variadic) -> R
@@ -296,20 +392,12 @@ where
         unsafe { (Value::new(LLVMGetParam(function, 0)),) }
     }
 
-    fn build_call(
-        builder: *mut LLVMBuilder,
-        function: *mut LLVMValue,
-        params: Self::Params,
-    ) -> Self::Return {
-        R::as_return_value(
-            builder,
-            build_call(
-                builder,
-                function,
-                Self::function_type(),
-                &[params.0.value()],
-            ),
-        )
+    fn param_values(params: &Self::Params) -> Vec<*mut LLVMValue> {
+        vec![params.0.value()]
+    }
+
+    fn return_value(builder: *mut LLVMBuilder, value: *mut LLVMValue) -> Self::Return {
+        R::as_return_value(builder, value)
     }
 }
 
@@ -324,13 +412,82 @@ where
         params: Self::Params,
         variadic_params: &[UntypedValue],
     ) -> Self::Return {
-        let mut all_params = Vec::with_capacity(1 + variadic_params.len());
-        all_params.push(params.0.value());
+        let mut all_params = Self::param_values(&params);
         all_params.extend(variadic_params.iter().map(UntypedValue::value));
 
-        R::as_return_value(
+        Self::return_value(
             builder,
             build_call(builder, function, Self::function_type(), &all_params),
         )
     }
 }
+
+#[macro_export]
+macro_rules! value_struct {
+    (@steps $name:ident, $index:expr;) => {};
+
+    (@steps $name:ident, $index:expr; $t:ty $(, $rest:ty)*) => {
+        impl $crate::Step<$name> for $crate::Field<{ $index }> {
+            type Output = $t;
+
+            fn index(&self) -> *mut $crate::__llvm_sys::LLVMValue {
+                $crate::Constant::constant(($index) as u32)
+            }
+        }
+
+        $crate::value_struct!(@steps $name, $index + 1; $($rest),*);
+    };
+
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident: $t:ty),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($field_vis $field: $t),*
+        }
+
+        impl $crate::ValueType for $name {
+            type ReturnType = $crate::Value<$name>;
+
+            fn value_type() -> *mut $crate::__llvm_sys::LLVMType {
+                unsafe {
+                    let mut types = [$(<$t as $crate::ValueType>::value_type()),*];
+
+                    $crate::__llvm_sys::core::LLVMStructType(
+                        types.as_mut_ptr(),
+                        types.len() as u32,
+                        0,
+                    )
+                }
+            }
+
+            fn as_return_value(
+                _: *mut $crate::__llvm_sys::LLVMBuilder,
+                value: *mut $crate::__llvm_sys::LLVMValue,
+            ) -> Self::ReturnType {
+                $crate::Value::new(value)
+            }
+        }
+
+        impl $crate::Constant for $name {
+            fn constant(self) -> *mut $crate::__llvm_sys::LLVMValue {
+                unsafe {
+                    let mut values = [$($crate::Constant::constant(self.$field)),*];
+
+                    $crate::__llvm_sys::core::LLVMConstStruct(
+                        values.as_mut_ptr(),
+                        values.len() as u32,
+                        0,
+                    )
+                }
+            }
+        }
+
+        impl $crate::PayloadType for $name {}
+
+        $crate::value_struct!(@steps $name, 0; $($t),*);
+    };
+}
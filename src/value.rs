@@ -1,37 +1,137 @@
-use crate::ValueType;
+use crate::{Builder, BuilderRef, ValueType, Vector};
 
 use std::marker::PhantomData;
 
-use llvm_sys::{core::LLVMConstInt, LLVMValue};
+use llvm_sys::{
+    core::{
+        LLVMConstArray, LLVMConstInt, LLVMConstNull, LLVMConstPointerNull, LLVMConstReal,
+        LLVMConstStruct, LLVMConstVector, LLVMIsAInstruction, LLVMIsALandingPadInst,
+        LLVMIsAPHINode, LLVMPositionBuilderBefore,
+    },
+    LLVMValue,
+};
 
 pub trait Constant: ValueType + Sized {
     fn constant(self) -> *mut LLVMValue;
 }
 
-pub trait Integer: Constant {
+pub trait Arithmetic: Constant {
+    const SIGNED: bool;
+
+    type Bool: Arithmetic;
+
     fn zero() -> *mut LLVMValue;
 }
 
+pub trait Integer: Arithmetic {}
+
+pub trait MultiByte: Arithmetic {}
+
+pub trait Float: Constant {}
+
 macro_rules! constant {
-    ($t:ty) => {
+    ($t:ty, $signed:expr) => {
         impl Constant for $t {
             fn constant(self) -> *mut LLVMValue {
                 unsafe { LLVMConstInt(Self::value_type(), self as u64, 0) }
             }
         }
 
-        impl Integer for $t {
+        impl Arithmetic for $t {
+            const SIGNED: bool = $signed;
+
+            type Bool = bool;
+
             fn zero() -> *mut LLVMValue {
                 (0 as Self).constant()
             }
         }
+
+        impl Integer for $t {}
     };
 }
 
-constant!(i32);
-constant!(i64);
-constant!(u32);
-constant!(u64);
+impl Constant for bool {
+    fn constant(self) -> *mut LLVMValue {
+        unsafe { LLVMConstInt(Self::value_type(), self as u64, 0) }
+    }
+}
+
+impl Arithmetic for bool {
+    const SIGNED: bool = false;
+
+    type Bool = bool;
+
+    fn zero() -> *mut LLVMValue {
+        false.constant()
+    }
+}
+
+impl Integer for bool {}
+
+constant!(i8, true);
+constant!(u8, false);
+constant!(i32, true);
+constant!(i64, true);
+constant!(u32, false);
+constant!(u64, false);
+constant!(usize, false);
+
+impl MultiByte for i32 {}
+impl MultiByte for i64 {}
+impl MultiByte for u32 {}
+impl MultiByte for u64 {}
+impl MultiByte for usize {}
+
+macro_rules! float {
+    ($t:ty) => {
+        impl Constant for $t {
+            fn constant(self) -> *mut LLVMValue {
+                unsafe { LLVMConstReal(Self::value_type(), self as f64) }
+            }
+        }
+
+        impl Float for $t {}
+    };
+}
+
+float!(f32);
+float!(f64);
+
+impl<T: Constant, const N: usize> Constant for [T; N] {
+    fn constant(self) -> *mut LLVMValue {
+        let mut values = self.map(Constant::constant);
+        unsafe { LLVMConstArray(T::value_type(), values.as_mut_ptr(), N as u32) }
+    }
+}
+
+impl<A: Constant, B: Constant> Constant for (A, B) {
+    fn constant(self) -> *mut LLVMValue {
+        let mut values = [self.0.constant(), self.1.constant()];
+        unsafe { LLVMConstStruct(values.as_mut_ptr(), 2, 0) }
+    }
+}
+
+impl<T: Constant, const N: usize> Constant for Vector<T, N> {
+    fn constant(self) -> *mut LLVMValue {
+        let mut values = self.0.map(Constant::constant);
+        unsafe { LLVMConstVector(values.as_mut_ptr(), N as u32) }
+    }
+}
+
+impl<T: Integer, const N: usize> Arithmetic for Vector<T, N> {
+    const SIGNED: bool = T::SIGNED;
+
+    type Bool = Vector<bool, N>;
+
+    fn zero() -> *mut LLVMValue {
+        unsafe { LLVMConstNull(Self::value_type()) }
+    }
+}
+
+impl<T: Integer + MultiByte, const N: usize> MultiByte for Vector<T, N> {}
+
+impl<T: Float, const N: usize> Float for Vector<T, N> {}
 
 #[derive(Copy, Clone)]
 pub struct UntypedValue {
@@ -44,12 +144,19 @@ impl UntypedValue {
     }
 }
 
-#[derive(Copy, Clone)]
 pub struct Value<T: ValueType + ?Sized> {
     value: *mut LLVMValue,
     phantom: PhantomData<T>,
 }
 
+impl<T: ValueType + ?Sized> Clone for Value<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ValueType + ?Sized> Copy for Value<T> {}
+
 impl<T: ValueType + ?Sized> Value<T> {
     pub fn new(value: *mut LLVMValue) -> Self {
         Self {
@@ -68,6 +175,25 @@ impl<T: ValueType + ?Sized> Value<T> {
         }
     }
 
+    pub fn build_before(&self) -> Option<BuilderRef<'static>> {
+        unsafe {
+            if LLVMIsAInstruction(self.value).is_null()
+                || !LLVMIsAPHINode(self.value).is_null()
+                || !LLVMIsALandingPadInst(self.value).is_null()
+            {
+                return None;
+            }
+        }
+
+        let builder = Builder::new();
+
+        unsafe {
+            LLVMPositionBuilderBefore(builder.builder, self.value);
+        }
+
+        Some(BuilderRef::owned(builder))
+    }
+
     pub fn untyped(&self) -> UntypedValue {
         UntypedValue { value: self.value }
     }
@@ -76,3 +202,9 @@ impl<T: ValueType + ?Sized> Value<T> {
         self.value
     }
 }
+
+impl<T: ValueType> Value<*mut T> {
+    pub fn null() -> Self {
+        Self::new(unsafe { LLVMConstPointerNull(<*mut T>::value_type()) })
+    }
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use dragon_tamer::{AtomicOp, Module, Ordering, Value};
+
+#[test]
+fn valid_orderings() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(*mut i32) -> i32>("function");
+    let (ptr,) = function.params();
+
+    let builder = function.add_block("entry").build();
+    let (value, builder) = builder.build_atomic_load(&ptr, Ordering::Acquire);
+    let builder = builder.build_atomic_store(&ptr, &value, Ordering::Release);
+    let (value, builder) = builder.build_atomic_rmw(AtomicOp::Max, &ptr, &value, Ordering::Relaxed);
+    let ((old, _), builder) = builder.build_cmpxchg(
+        &ptr,
+        &value,
+        &Value::constant(0),
+        Ordering::Relaxed,
+        Ordering::SeqCst,
+    );
+    let builder = builder.build_fence(Ordering::AcqRel);
+    builder.build_ret(&old);
+
+    common::verify(&module).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn release_load() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(*mut i32)>("function");
+    let (ptr,) = function.params();
+
+    let _ = function
+        .add_block("entry")
+        .build()
+        .build_atomic_load(&ptr, Ordering::Release);
+}
+
+#[test]
+#[should_panic]
+fn acquire_store() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(*mut i32)>("function");
+    let (ptr,) = function.params();
+
+    let _ = function.add_block("entry").build().build_atomic_store(
+        &ptr,
+        &Value::constant(0),
+        Ordering::Acquire,
+    );
+}
+
+#[test]
+#[should_panic]
+fn release_failure_ordering() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(*mut i32)>("function");
+    let (ptr,) = function.params();
+
+    let _ = function.add_block("entry").build().build_cmpxchg(
+        &ptr,
+        &Value::constant(0),
+        &Value::constant(1),
+        Ordering::SeqCst,
+        Ordering::Release,
+    );
+}
+
+#[test]
+#[should_panic]
+fn relaxed_fence() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn()>("function");
+
+    let _ = function
+        .add_block("entry")
+        .build()
+        .build_fence(Ordering::Relaxed);
+}
+
+#[test]
+fn byte_and_pointer_atomics() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(*mut u8, *mut *mut u8) -> u8>("function");
+    let (byte, ptr) = function.params();
+
+    let builder = function.add_block("entry").build();
+    let (value, builder) =
+        builder.build_atomic_rmw(AtomicOp::Add, &byte, &Value::constant(1), Ordering::SeqCst);
+    let (old, builder) = builder.build_atomic_load(&ptr, Ordering::Acquire);
+    let ((_, _), builder) =
+        builder.build_cmpxchg(&ptr, &old, &byte, Ordering::SeqCst, Ordering::Relaxed);
+    let builder = builder.build_atomic_store(&ptr, &byte, Ordering::Release);
+    builder.build_ret(&value);
+
+    common::verify(&module).unwrap();
+}
@@ -0,0 +1,51 @@
+use std::ffi::CStr;
+
+use dragon_tamer::Module;
+use llvm_sys::{
+    analysis::{LLVMVerifierFailureAction, LLVMVerifyModule},
+    core::{
+        LLVMContextCreate, LLVMContextDispose, LLVMCreateMemoryBufferWithMemoryRangeCopy,
+        LLVMDisposeMessage, LLVMDisposeModule,
+    },
+    ir_reader::LLVMParseIRInContext,
+};
+
+pub fn verify(module: &Module) -> Result<(), String> {
+    let ir = format!("{:?}", module);
+
+    unsafe {
+        let context = LLVMContextCreate();
+        let buffer = LLVMCreateMemoryBufferWithMemoryRangeCopy(
+            ir.as_ptr().cast(),
+            ir.len(),
+            c"module".as_ptr(),
+        );
+
+        let mut parsed = std::ptr::null_mut();
+        let mut message = std::ptr::null_mut();
+
+        let failed = LLVMParseIRInContext(context, buffer, &mut parsed, &mut message) != 0
+            || LLVMVerifyModule(
+                parsed,
+                LLVMVerifierFailureAction::LLVMReturnStatusAction,
+                &mut message,
+            ) != 0;
+
+        let result = if failed {
+            Err(CStr::from_ptr(message).to_string_lossy().into_owned())
+        } else {
+            Ok(())
+        };
+
+        if !message.is_null() {
+            LLVMDisposeMessage(message);
+        }
+
+        if !parsed.is_null() {
+            LLVMDisposeModule(parsed);
+        }
+
+        LLVMContextDispose(context);
+        result
+    }
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use dragon_tamer::{value_struct, Field, Module, Value};
+
+value_struct! {
+    #[derive(Copy, Clone)]
+    struct Pixel {
+        r: u8,
+        g: u8,
+        b: u8,
+        position: (i32, i32),
+    }
+}
+
+#[test]
+fn constant_structs_and_arrays() {
+    let module = Module::new("test", "test.bf");
+
+    let pixel = Pixel {
+        r: 1,
+        g: 2,
+        b: 3,
+        position: (4, 5),
+    };
+
+    let global = module.add_global(pixel);
+    let pixels = module.add_array_with([pixel, pixel]);
+    let table = module.add_array_with([[1u32, 2], [3, 4]]);
+
+    let function = module.add_function::<_, fn() -> Pixel>("function");
+    let builder = function.add_block("entry").build();
+    let (value, builder) = builder.build_load(&global);
+    builder.build_ret(&value);
+
+    let _ = (pixels, table);
+
+    common::verify(&module).unwrap();
+}
+
+#[test]
+fn struct_fields() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(*mut Pixel) -> i32>("function");
+    let (pixel,) = function.params();
+
+    let builder = function.add_block("entry").build();
+    let (green, builder) = builder.build_gep(&pixel, (Field::<1>,));
+    let builder = builder.build_store(&green, &Value::constant(0));
+
+    let (y, builder) = builder.build_gep(&pixel, (Field::<3>, Field::<1>));
+    let (y, builder) = builder.build_load(&y);
+
+    let (value, builder) = builder.build_load(&pixel);
+    let (position, builder) = builder.build_extract_field(&value, Field::<3>);
+    let (x, builder) = builder.build_extract_field(&position, Field::<0>);
+
+    let (sum, builder) = builder.build_add(&x, &y);
+    builder.build_ret(&sum);
+
+    common::verify(&module).unwrap();
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use dragon_tamer::{BlockAddress, Module, Value};
+
+#[test]
+fn indirect_branch() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(i32) -> i32>("function");
+    let (x,) = function.params();
+
+    let entry = function.add_block("entry");
+    let a = function.add_block("a");
+    let b = function.add_block("b");
+
+    let (cond, builder) = entry.build().build_eq(&x, &Value::constant(0));
+    let (address, builder) =
+        builder.build_conditional_value::<_, BlockAddress>(&cond, &a.address(), &b.address());
+    builder.build_indirect_branch(&address, &[&a, &b]);
+
+    a.build().build_ret(&Value::constant(1));
+    b.build().build_ret(&Value::constant(2));
+
+    common::verify(&module).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "entry block")]
+fn entry_block_address() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn()>("function");
+
+    let _ = function.add_block("entry").address();
+}
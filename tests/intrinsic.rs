@@ -0,0 +1,30 @@
+mod common;
+
+use dragon_tamer::{Abs, Bswap, Ctlz, Cttz, Module, Vector};
+
+#[test]
+fn immediate_flags_and_byte_swaps() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(i32, Vector<u32, 4>) -> i32>("function");
+    let (x, vector) = function.params();
+
+    let ctlz = module.add_intrinsic::<Ctlz<i32, false>>();
+    let cttz = module.add_intrinsic::<Cttz<i32, true>>();
+    let abs = module.add_intrinsic::<Abs<i32, true>>();
+    let bswap = module.add_intrinsic::<Bswap<i32>>();
+    let vector_bswap = module.add_intrinsic::<Bswap<Vector<u32, 4>>>();
+
+    let builder = function.add_block("entry").build();
+    let (value, builder) = builder.build_call(&ctlz, (x,));
+    let (value, builder) = builder.build_call(&cttz, (value,));
+    let (value, builder) = builder.build_call(&abs, (value,));
+    let (value, builder) = builder.build_call(&bswap, (value,));
+    let (_, builder) = builder.build_call(&vector_bswap, (vector,));
+    builder.build_ret(&value);
+
+    let ir = format!("{:?}", module);
+    assert!(ir.contains("@llvm.ctlz.i32(i32 %0, i1 false)"));
+    assert_eq!(ir.matches("i1 true)").count(), 2);
+
+    common::verify(&module).unwrap();
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use dragon_tamer::{JumpTableError, Module, Value};
+
+#[test]
+fn constant_and_range_cases() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(i32) -> i32>("function");
+    let (x,) = function.params();
+
+    let entry = function.add_block("entry");
+    let a = function.add_block("a");
+    let b = function.add_block("b");
+    let default = function.add_block("default");
+
+    let mut table = entry.build().build_jump_table(&x, &default);
+    table
+        .case_const(1, &a)
+        .unwrap()
+        .case_range(3..=5, &b)
+        .unwrap();
+    table.finish();
+
+    a.build().build_ret(&Value::constant(1));
+    b.build().build_ret(&Value::constant(2));
+    default.build().build_ret(&Value::constant(0));
+
+    common::verify(&module).unwrap();
+}
+
+#[test]
+fn duplicate_cases_are_rejected() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(i32) -> i32>("function");
+    let (x,) = function.params();
+
+    let entry = function.add_block("entry");
+    let a = function.add_block("a");
+    let default = function.add_block("default");
+
+    let mut table = entry.build().build_jump_table(&x, &default);
+    table.case_range(1..=3, &a).unwrap();
+
+    assert_eq!(
+        table.case_const(2, &a).err(),
+        Some(JumpTableError::Duplicate)
+    );
+    assert_eq!(
+        table.case_range(0..=1, &a).err(),
+        Some(JumpTableError::Duplicate)
+    );
+
+    // The table survives the errors and the rejected range was not partially added
+    table.finish();
+    a.build().build_ret(&Value::constant(1));
+    default.build().build_ret(&Value::constant(0));
+
+    common::verify(&module).unwrap();
+    assert!(!format!("{:?}", module).contains("i32 0, label %a"));
+}
+
+#[test]
+fn non_constant_cases_are_rejected() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(i32) -> i32>("function");
+    let (x,) = function.params();
+
+    let entry = function.add_block("entry");
+    let a = function.add_block("a");
+    let default = function.add_block("default");
+
+    let (y, builder) = entry.build().build_add(&x, &x);
+    let mut table = builder.build_jump_table(&x, &default);
+
+    assert_eq!(table.case(&y, &a).err(), Some(JumpTableError::NonConstant));
+
+    table.finish();
+    a.build().build_ret(&Value::constant(1));
+    default.build().build_ret(&Value::constant(0));
+
+    common::verify(&module).unwrap();
+}
+
+#[test]
+fn deferred_cases() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(i32) -> i32>("function");
+    let (x,) = function.params();
+
+    let entry = function.add_block("entry");
+    let a = function.add_block("a");
+    let b = function.add_block("b");
+    let default = function.add_block("default");
+
+    let mut table = entry.build().build_jump_table(&x, &default);
+    table.case_const(1, &a).unwrap();
+    let switch = table.finish();
+
+    switch.case_range(2..=4, &b).unwrap();
+    switch.case_const(7, &a).unwrap();
+
+    assert_eq!(switch.case_const(1, &b), Err(JumpTableError::Duplicate));
+    assert_eq!(switch.case_const(3, &a), Err(JumpTableError::Duplicate));
+    assert_eq!(switch.case_range(5..=7, &b), Err(JumpTableError::Duplicate));
+
+    a.build().build_ret(&Value::constant(1));
+    b.build().build_ret(&Value::constant(2));
+    default.build().build_ret(&Value::constant(0));
+
+    common::verify(&module).unwrap();
+
+    // The rejected range must not have been partially added
+    assert!(!format!("{:?}", module).contains("i32 5, label %b"));
+}
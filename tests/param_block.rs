@@ -0,0 +1,20 @@
+mod common;
+
+use dragon_tamer::{Module, Value};
+
+#[test]
+fn conditional_jump_to_the_same_param_block() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(i32) -> i32>("function");
+    let (x,) = function.params();
+
+    let entry = function.add_block("entry");
+    let (block, (param,)) = function.add_block_with_params::<(i32,), _>("block");
+
+    let (cond, builder) = entry.build().build_eq(&x, &Value::constant(0));
+    let (sum, builder) = builder.build_add(&x, &x);
+    builder.build_conditional_jump_with_params(&cond, &block, (x,), &block, (sum,));
+    block.build().build_ret(&param);
+
+    common::verify(&module).unwrap();
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use dragon_tamer::{AtomicOp, MemFlags, Module, Ordering, Value};
+
+#[test]
+fn positioned_builders() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(i32) -> i32>("function");
+    let (x,) = function.params();
+
+    let entry = function.add_block("entry");
+    let (block, (param,)) = function.add_block_with_params::<(i32,), _>("block");
+
+    let (sum, builder) = entry.build().build_add(&x, &x);
+    builder.build_jump_with_params(&block, (sum,));
+
+    let (product, builder) = block.build().build_mul(&param, &param);
+    builder.build_ret(&product);
+
+    block.build_at_start().build_sub(&param, &x);
+    block.build_before_terminator().build_add(&product, &x);
+    sum.build_before().unwrap().build_mul(&x, &x);
+
+    assert!(x.build_before().is_none());
+    assert!(param.build_before().is_none());
+    assert!(Value::constant(1).build_before().is_none());
+
+    common::verify(&module).unwrap();
+}
+
+#[test]
+fn straight_line_instructions_before_a_terminator() {
+    let module = Module::new("test", "test.bf");
+    let callee = module.add_function::<_, fn(i32) -> i32>("callee");
+    let function = module.add_function::<_, fn(*mut [i32; 4], i32) -> i32>("function");
+    let (array, x) = function.params();
+
+    let entry = function.add_block("entry");
+    let (block, (param,)) = function.add_block_with_params::<(i32,), _>("block");
+
+    let (value, builder) = entry.build().build_call(&callee, (x,));
+    builder.build_jump_with_params(&block, (value,));
+    block.build().build_ret(&param);
+
+    let mut builder = entry.build_before_terminator();
+    let local = builder.build_local::<i32, _>("local");
+    let loaded = builder.build_index_load(&array, &x);
+    builder.build_store(&local, &loaded);
+    builder.build_atomic_rmw(AtomicOp::Add, &local, &x, Ordering::SeqCst);
+    builder.build_memset(&array, &Value::constant(0), MemFlags::default());
+
+    let mut builder = block.build_at_start();
+    builder.build_call(&callee, (param,));
+    builder.build_index_store(&array, &param, &x);
+
+    let mut builder = value.build_before().unwrap();
+    builder.build_call(&callee, (x,));
+    builder.build_fence(Ordering::SeqCst);
+
+    common::verify(&module).unwrap();
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use dragon_tamer::{Module, OutOfBounds, Slice};
+
+#[test]
+fn bounds_checks_share_a_trap_block() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(Slice<i32>, i32) -> i32>("function");
+    let (slice, index) = function.params();
+
+    let builder = function.add_block("entry").build();
+    let (a, builder) = builder.build_slice_index_load(&slice, &index, OutOfBounds::Trap);
+    let (b, builder) = builder.build_slice_index_load(&slice, &a, OutOfBounds::Trap);
+    let builder = builder.build_slice_index_store(&slice, &b, &a, OutOfBounds::Trap);
+    builder.build_ret(&b);
+
+    common::verify(&module).unwrap();
+
+    assert_eq!(
+        format!("{:?}", module)
+            .matches("call void @llvm.trap()")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn bounds_checks_in_later_blocks_share_a_trap_block() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(Slice<i32>, i32) -> i32>("function");
+    let (slice, index) = function.params();
+
+    let entry = function.add_block("entry");
+    let next = function.add_block("next");
+
+    let (a, builder) = entry
+        .build()
+        .build_slice_index_load(&slice, &index, OutOfBounds::Trap);
+    builder.build_jump(&next);
+
+    let (b, builder) = next
+        .build()
+        .build_slice_index_load(&slice, &a, OutOfBounds::Trap);
+    builder.build_ret(&b);
+
+    common::verify(&module).unwrap();
+
+    assert_eq!(
+        format!("{:?}", module)
+            .matches("call void @llvm.trap()")
+            .count(),
+        1
+    );
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use dragon_tamer::{Module, Value};
+
+#[test]
+fn unit_variants() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(i32) -> i32>("function");
+    let (x,) = function.params();
+
+    let entry = function.add_block("entry");
+    let some = function.add_block("some");
+    let none = function.add_block("none");
+
+    let builder = entry.build();
+    let (value, builder) = builder.build_variant::<(i32, ()), 0>(&x);
+    let (empty, builder) = builder.build_unit_variant::<(i32, ()), 1>();
+    let builder = builder.build_store_tag::<(i32, ()), 1>(&value);
+
+    let mut arms = builder.build_match(&empty, &none);
+    let payload = arms.arm::<0>(&some).unwrap();
+    arms.case::<1>(&none).unwrap();
+    arms.finish();
+
+    let (loaded, builder) = some.build().build_load(&payload);
+    builder.build_ret(&loaded);
+    none.build().build_ret(&Value::constant(0));
+
+    common::verify(&module).unwrap();
+}
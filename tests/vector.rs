@@ -0,0 +1,31 @@
+mod common;
+
+use dragon_tamer::{Module, Vector};
+
+#[test]
+fn lane_wise_arithmetic_and_shuffle() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(Vector<i32, 4>) -> Vector<i32, 2>>("function");
+    let (v,) = function.params();
+
+    let builder = function.add_block("entry").build();
+    let (sum, builder) = builder.build_add(&v, &v);
+    let (shifted, builder) = builder.build_shr(&sum, &v);
+    let (shuffled, builder) = builder.build_shuffle(&shifted, &v, [7, 0]);
+    builder.build_ret(&shuffled);
+
+    common::verify(&module).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn out_of_range_shuffle_mask() {
+    let module = Module::new("test", "test.bf");
+    let function = module.add_function::<_, fn(Vector<i32, 4>)>("function");
+    let (v,) = function.params();
+
+    let _ = function
+        .add_block("entry")
+        .build()
+        .build_shuffle(&v, &v, [9, 0]);
+}